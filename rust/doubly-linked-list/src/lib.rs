@@ -3,15 +3,125 @@
 // You are free to use anything in it, but it's mainly for the test framework.
 mod pre_implemented;
 
-pub struct LinkedList<T>(std::marker::PhantomData<T>);
+use std::iter::FusedIterator;
+use std::marker::PhantomData;
+use std::ptr::NonNull;
 
-pub struct Cursor<'a, T>(std::marker::PhantomData<&'a mut T>);
+// Nodes are allocated with `Box` and owned by the list, which frees them
+// when they are taken or the list is dropped. Every link points at a live
+// node of the same list.
+type Link<T> = Option<NonNull<Node<T>>>;
 
-pub struct Iter<'a, T>(std::marker::PhantomData<&'a T>);
+struct Node<T> {
+    element: T,
+    // The neighbours of this node, indexed by `Direction`.
+    links: [Link<T>; 2],
+}
+
+// The two ways along a list. Operations that mirror each other take a
+// direction rather than being written twice.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Direction {
+    // Towards the back.
+    Next,
+    // Towards the front.
+    Prev,
+}
+
+use Direction::{Next, Prev};
+
+impl Direction {
+    fn opposite(self) -> Direction {
+        match self {
+            Next => Prev,
+            Prev => Next,
+        }
+    }
+}
+
+impl<T> Node<T> {
+    // The node after `node` in `direction`.
+    //
+    // Safety: `node` must be a live node.
+    unsafe fn link(node: NonNull<Node<T>>, direction: Direction) -> Link<T> {
+        unsafe { (*node.as_ptr()).links[direction as usize] }
+    }
+
+    // Safety: `node` must be a live node.
+    unsafe fn set_link(node: NonNull<Node<T>>, direction: Direction, link: Link<T>) {
+        unsafe { (*node.as_ptr()).links[direction as usize] = link }
+    }
+}
+
+pub struct LinkedList<T> {
+    // The last node in each direction: the back, then the front.
+    ends: [Link<T>; 2],
+    len: usize,
+    // The list owns its nodes, and so the elements in them.
+    marker: PhantomData<Box<Node<T>>>,
+}
+
+pub struct Cursor<'a, T> {
+    list: &'a mut LinkedList<T>,
+    position: Position<T>,
+}
+
+/// A cursor that can only read the list, so that several can walk a
+/// shared list at once
+pub struct ReadCursor<'a, T> {
+    list: &'a LinkedList<T>,
+    position: Position<T>,
+}
+
+// Where a cursor is: at a node, or at the ghost position when `current` is
+// `None`. The index of the node from the front is kept so that splitting
+// can tell how long each part is without counting; the ghost's is the
+// length of the list.
+struct Position<T> {
+    current: Link<T>,
+    index: usize,
+}
+
+pub struct Iter<'a, T> {
+    remaining: Remaining<T>,
+    marker: PhantomData<&'a T>,
+}
+
+pub struct IterMut<'a, T> {
+    remaining: Remaining<T>,
+    marker: PhantomData<&'a mut T>,
+}
+
+pub struct IntoIter<T> {
+    list: LinkedList<T>,
+}
+
+// The nodes an iterator has not yet visited from either end.
+struct Remaining<T> {
+    ends: [Link<T>; 2],
+    len: usize,
+}
+
+// The raw links stop these from being `Send` and `Sync` automatically, but
+// they behave like a `Box<T>`, `&mut T` and `&T` of the elements.
+unsafe impl<T: Send> Send for LinkedList<T> {}
+unsafe impl<T: Sync> Sync for LinkedList<T> {}
+unsafe impl<T: Send> Send for Cursor<'_, T> {}
+unsafe impl<T: Sync> Sync for Cursor<'_, T> {}
+unsafe impl<T: Sync> Send for ReadCursor<'_, T> {}
+unsafe impl<T: Sync> Sync for ReadCursor<'_, T> {}
+unsafe impl<T: Sync> Send for Iter<'_, T> {}
+unsafe impl<T: Sync> Sync for Iter<'_, T> {}
+unsafe impl<T: Send> Send for IterMut<'_, T> {}
+unsafe impl<T: Sync> Sync for IterMut<'_, T> {}
 
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
-        todo!()
+        LinkedList {
+            ends: [None, None],
+            len: 0,
+            marker: PhantomData,
+        }
     }
 
     // You may be wondering why it's necessary to have is_empty()
@@ -20,70 +130,473 @@ impl<T> LinkedList<T> {
     // whereas is_empty() is almost always cheap.
     // (Also ask yourself whether len() is expensive for LinkedList)
     pub fn is_empty(&self) -> bool {
-        todo!()
+        self.len == 0
     }
 
     pub fn len(&self) -> usize {
-        todo!()
+        self.len
     }
 
     /// Return a cursor positioned on the front element
     pub fn cursor_front(&mut self) -> Cursor<'_, T> {
-        todo!()
+        self.cursor(Prev)
     }
 
     /// Return a cursor positioned on the back element
     pub fn cursor_back(&mut self) -> Cursor<'_, T> {
-        todo!()
+        self.cursor(Next)
     }
 
     /// Return an iterator that moves from front to back
     pub fn iter(&self) -> Iter<'_, T> {
-        todo!()
+        Iter {
+            remaining: Remaining::of(self),
+            marker: PhantomData,
+        }
+    }
+
+    /// Return an iterator that moves from front to back, allowing each
+    /// element to be changed
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            remaining: Remaining::of(self),
+            marker: PhantomData,
+        }
+    }
+
+    // The last node in `direction`.
+    fn end(&self, direction: Direction) -> Link<T> {
+        self.ends[direction as usize]
+    }
+
+    /// Return a read-only cursor positioned on the front element
+    pub fn read_cursor_front(&self) -> ReadCursor<'_, T> {
+        ReadCursor {
+            position: Position::end(self, Prev),
+            list: self,
+        }
+    }
+
+    /// Return a read-only cursor positioned on the back element
+    pub fn read_cursor_back(&self) -> ReadCursor<'_, T> {
+        ReadCursor {
+            position: Position::end(self, Next),
+            list: self,
+        }
+    }
+
+    fn cursor(&mut self, direction: Direction) -> Cursor<'_, T> {
+        Cursor {
+            position: Position::end(self, direction),
+            list: self,
+        }
+    }
+
+    /// Move all elements of `other` to the back of this list, leaving
+    /// `other` empty
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        let other = std::mem::replace(other, LinkedList::new());
+        self.cursor_back().splice_after(other);
+    }
+}
+
+impl<T> Drop for LinkedList<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
     }
 }
 
 // the cursor is expected to act as if it is at the position of an element
 // and it also has to work with and be able to insert into an empty list.
+//
+// Moving past either end of the list reaches a "ghost" position, where
+// there is no element. Moving on from the ghost wraps around to the other
+// end, and an empty list only has the ghost.
 impl<T> Cursor<'_, T> {
     /// Take a mutable reference to the current element
     pub fn peek_mut(&mut self) -> Option<&mut T> {
-        todo!()
+        // The cursor borrows the list mutably, so no other reference to
+        // the element can exist while this one does.
+        self.position
+            .current
+            .map(|node| unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    /// The position of the current element from the front, or `None` at
+    /// the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.position.index()
     }
 
     /// Move one position forward (towards the back) and
     /// return a reference to the new position
     #[allow(clippy::should_implement_trait)]
     pub fn next(&mut self) -> Option<&mut T> {
-        todo!()
+        self.position.step(self.list, Next);
+        self.peek_mut()
     }
 
     /// Move one position backward (towards the front) and
     /// return a reference to the new position
     pub fn prev(&mut self) -> Option<&mut T> {
-        todo!()
+        self.position.step(self.list, Prev);
+        self.peek_mut()
     }
 
     /// Remove and return the element at the current position and move the cursor
     /// to the neighboring element that's closest to the back. This can be
     /// either the next or previous position.
     pub fn take(&mut self) -> Option<T> {
-        todo!()
+        let node = self.position.current?;
+        // The node was allocated by `insert` and is unlinked here, so this
+        // is the only owner of it.
+        let node = unsafe { Box::from_raw(node.as_ptr()) };
+        let [next, prev] = node.links;
+        for (direction, neighbour, other) in [(Next, next, prev), (Prev, prev, next)] {
+            match neighbour {
+                Some(neighbour) => unsafe {
+                    Node::set_link(neighbour, direction.opposite(), other)
+                },
+                None => self.list.ends[direction as usize] = other,
+            }
+        }
+        self.position.current = next.or(prev);
+        if next.is_none() {
+            self.position.index = self.position.index.saturating_sub(1);
+        }
+        self.list.len -= 1;
+        Some(node.element)
+    }
+
+    /// Insert after the current element, or at the front from the ghost
+    pub fn insert_after(&mut self, element: T) {
+        self.insert(Next, element);
+    }
+
+    /// Insert before the current element, or at the back from the ghost
+    pub fn insert_before(&mut self, element: T) {
+        self.insert(Prev, element);
+    }
+
+    /// Move the elements after the cursor into a new list. From the ghost,
+    /// that is the whole list.
+    pub fn split_after(&mut self) -> LinkedList<T> {
+        self.split(Next)
+    }
+
+    /// Move the elements before the cursor into a new list. From the
+    /// ghost, that is the whole list.
+    pub fn split_before(&mut self) -> LinkedList<T> {
+        self.split(Prev)
     }
 
-    pub fn insert_after(&mut self, _element: T) {
-        todo!()
+    /// Move all elements of `other` to just after the cursor, or to the
+    /// front from the ghost
+    pub fn splice_after(&mut self, other: LinkedList<T>) {
+        self.splice(Next, other);
     }
 
-    pub fn insert_before(&mut self, _element: T) {
-        todo!()
+    /// Move all elements of `other` to just before the cursor, or to the
+    /// back from the ghost
+    pub fn splice_before(&mut self, other: LinkedList<T>) {
+        self.splice(Prev, other);
+    }
+
+    fn insert(&mut self, direction: Direction, element: T) {
+        let node = NonNull::from(Box::leak(Box::new(Node {
+            element,
+            links: [None, None],
+        })));
+        unsafe { self.link_chain(direction, node, node, 1) };
+    }
+
+    // Link all of `other` in next to the cursor in `direction`.
+    fn splice(&mut self, direction: Direction, mut other: LinkedList<T>) {
+        let ends = std::mem::take(&mut other.ends);
+        let len = std::mem::replace(&mut other.len, 0);
+        if let (Some(near), Some(far)) = (
+            ends[direction.opposite() as usize],
+            ends[direction as usize],
+        ) {
+            unsafe { self.link_chain(direction, near, far, len) };
+        }
+    }
+
+    // Link the chain of `len` nodes from `near` to `far` in next to the
+    // cursor in `direction`. From the ghost, the chain goes at the opposite
+    // end of the list.
+    //
+    // Safety: the chain must be live nodes not in any list.
+    unsafe fn link_chain(
+        &mut self,
+        direction: Direction,
+        near: NonNull<Node<T>>,
+        far: NonNull<Node<T>>,
+        len: usize,
+    ) {
+        let current = self.position.current;
+        let neighbour = match current {
+            Some(current) => unsafe { Node::link(current, direction) },
+            None => self.list.end(direction.opposite()),
+        };
+        unsafe {
+            Node::set_link(near, direction.opposite(), current);
+            match current {
+                Some(current) => Node::set_link(current, direction, Some(near)),
+                None => self.list.ends[direction.opposite() as usize] = Some(near),
+            }
+            Node::set_link(far, direction, neighbour);
+            match neighbour {
+                Some(neighbour) => Node::set_link(neighbour, direction.opposite(), Some(far)),
+                None => self.list.ends[direction as usize] = Some(far),
+            }
+        }
+        // The ghost counts as being after the back.
+        if direction == Prev || current.is_none() {
+            self.position.index += len;
+        }
+        self.list.len += len;
+    }
+
+    // Unlink the nodes beyond the cursor in `direction` into a new list.
+    fn split(&mut self, direction: Direction) -> LinkedList<T> {
+        let Some(current) = self.position.current else {
+            self.position.index = 0;
+            return std::mem::replace(self.list, LinkedList::new());
+        };
+        let Some(near) = (unsafe { Node::link(current, direction) }) else {
+            return LinkedList::new();
+        };
+        let len = match direction {
+            Next => self.list.len - self.position.index - 1,
+            Prev => std::mem::replace(&mut self.position.index, 0),
+        };
+        unsafe {
+            Node::set_link(current, direction, None);
+            Node::set_link(near, direction.opposite(), None);
+        }
+        let mut split = LinkedList::new();
+        split.ends[direction as usize] = self.list.ends[direction as usize].replace(current);
+        split.ends[direction.opposite() as usize] = Some(near);
+        split.len = len;
+        self.list.len -= len;
+        split
     }
 }
 
+impl<T> Position<T> {
+    // At the last element in `direction`, or the ghost if there is none.
+    fn end(list: &LinkedList<T>, direction: Direction) -> Self {
+        Position {
+            current: list.end(direction),
+            index: match direction {
+                Next => list.len.saturating_sub(1),
+                Prev => 0,
+            },
+        }
+    }
+
+    fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    fn step(&mut self, list: &LinkedList<T>, direction: Direction) {
+        *self = match self.current {
+            None => Position::end(list, direction.opposite()),
+            Some(current) => match unsafe { Node::link(current, direction) } {
+                Some(neighbour) => Position {
+                    current: Some(neighbour),
+                    index: match direction {
+                        Next => self.index + 1,
+                        Prev => self.index - 1,
+                    },
+                },
+                None => Position {
+                    current: None,
+                    index: list.len,
+                },
+            },
+        }
+    }
+}
+
+impl<T> Clone for Position<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Position<T> {}
+
+impl<'a, T> ReadCursor<'a, T> {
+    /// The current element, or `None` at the ghost position
+    pub fn peek(&self) -> Option<&'a T> {
+        // The cursor borrows the list, so its nodes outlive `'a`.
+        self.position
+            .current
+            .map(|node| unsafe { &(*node.as_ptr()).element })
+    }
+
+    /// The position of the current element from the front, or `None` at
+    /// the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.position.index()
+    }
+
+    /// Move one position forward (towards the back) and
+    /// return a reference to the new position
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&'a T> {
+        self.position.step(self.list, Next);
+        self.peek()
+    }
+
+    /// Move one position backward (towards the front) and
+    /// return a reference to the new position
+    pub fn prev(&mut self) -> Option<&'a T> {
+        self.position.step(self.list, Prev);
+        self.peek()
+    }
+}
+
+impl<T> Clone for ReadCursor<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ReadCursor<'_, T> {}
+
+impl<T> Remaining<T> {
+    fn of(list: &LinkedList<T>) -> Self {
+        Remaining {
+            ends: list.ends,
+            len: list.len,
+        }
+    }
+
+    // Take the node at the `end` of what remains. The count stops the two
+    // ends from passing each other.
+    fn take(&mut self, end: Direction) -> Link<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = self.ends[end as usize]?;
+        self.ends[end as usize] = unsafe { Node::link(node, end.opposite()) };
+        self.len -= 1;
+        Some(node)
+    }
+}
+
+impl<T> Clone for Iter<'_, T> {
+    fn clone(&self) -> Self {
+        Iter {
+            remaining: Remaining {
+                ends: self.remaining.ends,
+                len: self.remaining.len,
+            },
+            marker: PhantomData,
+        }
+    }
+}
+
+// Iterators take elements from the front with `next` and from the back
+// with `next_back`. Each borrows or owns the list, so its nodes live as
+// long as the elements it returns.
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
-        todo!()
+        let node = self.remaining.take(Prev)?;
+        Some(unsafe { &(*node.as_ptr()).element })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining.len, Some(self.remaining.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node = self.remaining.take(Next)?;
+        Some(unsafe { &(*node.as_ptr()).element })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<T> FusedIterator for Iter<'_, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        // Each node is taken once, so the references never alias.
+        let node = self.remaining.take(Prev)?;
+        Some(unsafe { &mut (*node.as_ptr()).element })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining.len, Some(self.remaining.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let node = self.remaining.take(Next)?;
+        Some(unsafe { &mut (*node.as_ptr()).element })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<T> FusedIterator for IterMut<'_, T> {}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.list.len, Some(self.list.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> FusedIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for LinkedList<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { list: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
     }
 }
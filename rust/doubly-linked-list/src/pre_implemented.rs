@@ -75,3 +75,45 @@ pub struct IllegalSend;
 /// impl<T> AssertSync for LinkedList<T> {}
 /// ```
 pub struct IllegalSync;
+
+#[allow(unused)]
+#[cfg(feature = "advanced")]
+/// A list is only `Send` when its elements are.
+/// ```compile_fail
+/// use doubly_linked_list::LinkedList;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<LinkedList<std::rc::Rc<i32>>>();
+/// ```
+pub struct IllegalElementSend;
+
+#[allow(unused)]
+#[cfg(feature = "advanced")]
+/// A list is only `Sync` when its elements are.
+/// ```compile_fail
+/// use doubly_linked_list::LinkedList;
+/// fn assert_sync<T: Sync>() {}
+/// assert_sync::<LinkedList<std::cell::Cell<i32>>>();
+/// ```
+pub struct IllegalElementSync;
+
+#[allow(unused)]
+#[cfg(feature = "advanced")]
+/// An iterator shares the elements, so it is only `Send` when they are
+/// `Sync`.
+/// ```compile_fail
+/// use doubly_linked_list::Iter;
+/// fn assert_send<T: Send>() {}
+/// assert_send::<Iter<'static, std::cell::Cell<i32>>>();
+/// ```
+pub struct IllegalIterSend;
+
+#[allow(unused)]
+#[cfg(feature = "advanced")]
+/// A cursor can change the list, so it must not be covariant.
+/// ```compile_fail
+/// use doubly_linked_list::Cursor;
+/// fn shorten<'a, 'b>(cursor: Cursor<'a, &'static str>) -> Cursor<'a, &'b str> {
+///     cursor
+/// }
+/// ```
+pub struct IllegalCursorVariance;
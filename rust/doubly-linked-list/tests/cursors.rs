@@ -0,0 +1,154 @@
+use doubly_linked_list::*;
+
+#[test]
+fn read_cursors_walk_a_shared_list() {
+    let list = (0..3).collect::<LinkedList<_>>();
+    let mut front = list.read_cursor_front();
+    let mut back = list.read_cursor_back();
+    assert_eq!(front.peek(), Some(&0));
+    assert_eq!(back.peek(), Some(&2));
+    assert_eq!(front.next(), Some(&1));
+    assert_eq!(back.prev(), Some(&1));
+    assert_eq!(front.next(), Some(&2));
+    assert_eq!(list.front(), Some(&0));
+}
+
+#[test]
+fn read_cursors_can_be_copied() {
+    let list = (0..3).collect::<LinkedList<_>>();
+    let mut cursor = list.read_cursor_front();
+    let start = cursor;
+    cursor.next();
+    assert_eq!(start.peek(), Some(&0));
+    assert_eq!(cursor.peek(), Some(&1));
+}
+
+#[test]
+fn cursors_track_their_index() {
+    let mut list = (0..5).collect::<LinkedList<_>>();
+    assert_eq!(list.read_cursor_back().index(), Some(4));
+
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.index(), Some(0));
+    cursor.seek_forward(2);
+    assert_eq!(cursor.index(), Some(2));
+    cursor.insert_before(10);
+    assert_eq!(cursor.index(), Some(3));
+    cursor.insert_after(11);
+    assert_eq!(cursor.index(), Some(3));
+    cursor.prev();
+    assert_eq!(cursor.index(), Some(2));
+    cursor.take();
+    assert_eq!(cursor.index(), Some(2));
+    assert_eq!(cursor.peek_mut(), Some(&mut 2));
+}
+
+#[test]
+fn moving_past_an_end_reaches_the_ghost() {
+    let mut list = (0..2).collect::<LinkedList<_>>();
+    let mut cursor = list.cursor_back();
+    assert_eq!(cursor.next(), None);
+    assert_eq!(cursor.index(), None);
+    assert_eq!(cursor.peek_mut(), None);
+    assert_eq!(cursor.next(), Some(&mut 0));
+    assert_eq!(cursor.index(), Some(0));
+    assert_eq!(cursor.prev(), None);
+    assert_eq!(cursor.prev(), Some(&mut 1));
+    assert_eq!(cursor.index(), Some(1));
+}
+
+#[test]
+fn read_cursors_wrap_around_too() {
+    let list = (0..3).collect::<LinkedList<_>>();
+    let mut cursor = list.read_cursor_front();
+    let walked: Vec<_> = std::iter::from_fn(|| Some(cursor.prev())).take(8).collect();
+    assert_eq!(
+        walked,
+        [
+            None,
+            Some(&2),
+            Some(&1),
+            Some(&0),
+            None,
+            Some(&2),
+            Some(&1),
+            Some(&0)
+        ]
+    );
+}
+
+#[test]
+fn an_empty_list_only_has_the_ghost() {
+    let mut list = LinkedList::<i32>::new();
+    assert_eq!(list.read_cursor_front().index(), None);
+    let mut cursor = list.cursor_front();
+    assert_eq!(cursor.next(), None);
+    assert_eq!(cursor.prev(), None);
+    assert_eq!(cursor.take(), None);
+    assert!(cursor.split_after().is_empty());
+}
+
+#[test]
+fn inserting_at_the_ghost_goes_to_the_ends() {
+    let mut list = (1..3).collect::<LinkedList<_>>();
+    {
+        let mut cursor = list.cursor_back();
+        cursor.next();
+        cursor.insert_after(0);
+        cursor.insert_before(3);
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.next(), Some(&mut 0));
+    }
+    assert!(list.iter().copied().eq(0..4));
+}
+
+#[test]
+fn splitting_at_the_ghost_takes_everything() {
+    let mut list = (0..3).collect::<LinkedList<_>>();
+    let mut cursor = list.cursor_back();
+    cursor.next();
+    let all = cursor.split_before();
+    assert_eq!(cursor.next(), None);
+    assert!(list.is_empty());
+    assert!(all.iter().copied().eq(0..3));
+}
+
+#[test]
+fn cursors_keep_their_index_after_a_split() {
+    let mut list = (0..6).collect::<LinkedList<_>>();
+    let mut cursor = list.cursor_front();
+    cursor.next();
+    cursor.next();
+    assert_eq!(cursor.split_after().len(), 3);
+    assert_eq!(cursor.index(), Some(2));
+    cursor.prev();
+    assert_eq!(cursor.split_before().len(), 1);
+    assert_eq!(cursor.index(), Some(0));
+    assert_eq!(cursor.next(), Some(&mut 2));
+    assert_eq!(cursor.index(), Some(1));
+
+    cursor.next();
+    assert_eq!(cursor.split_after().len(), 2);
+    cursor.insert_before(7);
+    cursor.splice_before((8..10).collect());
+    assert_eq!(cursor.index(), None);
+    assert_eq!(cursor.prev(), Some(&mut 9));
+    assert_eq!(cursor.index(), Some(2));
+    assert_eq!(cursor.split_before().len(), 2);
+    assert_eq!(cursor.index(), Some(0));
+}
+
+#[test]
+fn splicing_at_the_ghost_goes_to_the_ends() {
+    let mut list = (2..4).collect::<LinkedList<_>>();
+    {
+        let mut cursor = list.cursor_front();
+        cursor.prev();
+        cursor.splice_after((0..2).collect());
+        cursor.splice_before((4..6).collect());
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.prev(), Some(&mut 5));
+        assert_eq!(cursor.index(), Some(5));
+    }
+    assert!(list.iter().copied().eq(0..6));
+}
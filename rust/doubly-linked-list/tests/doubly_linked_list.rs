@@ -11,7 +11,6 @@ fn is_generic() {
 // ———————————————————————————————————————————————————————————
 
 #[test]
-#[ignore]
 fn basics_empty_list() {
     let list: LinkedList<i32> = LinkedList::new();
     assert_eq!(list.len(), 0);
@@ -20,7 +19,6 @@ fn basics_empty_list() {
 
 // push / pop at back ————————————————————————————————————————
 #[test]
-#[ignore]
 fn basics_single_element_back() {
     let mut list: LinkedList<i32> = LinkedList::new();
     list.push_back(5);
@@ -35,7 +33,6 @@ fn basics_single_element_back() {
 }
 
 #[test]
-#[ignore]
 fn basics_push_pop_at_back() {
     let mut list: LinkedList<i32> = LinkedList::new();
     for i in 0..10 {
@@ -54,7 +51,6 @@ fn basics_push_pop_at_back() {
 
 // push / pop at front ———————————————————————————————————————
 #[test]
-#[ignore]
 fn basics_single_element_front() {
     let mut list: LinkedList<i32> = LinkedList::new();
     list.push_front(5);
@@ -69,7 +65,6 @@ fn basics_single_element_front() {
 }
 
 #[test]
-#[ignore]
 fn basics_push_pop_at_front() {
     let mut list: LinkedList<i32> = LinkedList::new();
     for i in 0..10 {
@@ -88,7 +83,6 @@ fn basics_push_pop_at_front() {
 
 // push / pop at mixed sides —————————————————————————————————
 #[test]
-#[ignore]
 fn basics_push_front_pop_back() {
     let mut list: LinkedList<i32> = LinkedList::new();
     for i in 0..10 {
@@ -106,7 +100,6 @@ fn basics_push_front_pop_back() {
 }
 
 #[test]
-#[ignore]
 fn basics_push_back_pop_front() {
     let mut list: LinkedList<i32> = LinkedList::new();
     for i in 0..10 {
@@ -128,7 +121,6 @@ fn basics_push_back_pop_front() {
 // ———————————————————————————————————————————————————————————
 
 #[test]
-#[ignore]
 fn iter() {
     let mut list: LinkedList<i32> = LinkedList::new();
     for num in 0..10 {
@@ -145,7 +137,6 @@ fn iter() {
 // ———————————————————————————————————————————————————————————
 
 #[test]
-#[ignore]
 fn cursor_insert_before_on_empty_list() {
     // insert_after on empty list is already tested via push_back()
     let mut list = LinkedList::new();
@@ -154,7 +145,6 @@ fn cursor_insert_before_on_empty_list() {
 }
 
 #[test]
-#[ignore]
 fn cursor_insert_after_in_middle() {
     let mut list = (0..10).collect::<LinkedList<_>>();
 
@@ -176,7 +166,6 @@ fn cursor_insert_after_in_middle() {
 }
 
 #[test]
-#[ignore]
 fn cursor_insert_before_in_middle() {
     let mut list = (0..10).collect::<LinkedList<_>>();
 
@@ -199,7 +188,6 @@ fn cursor_insert_before_in_middle() {
 
 // "iterates" via next() and checks that it visits the right elements
 #[test]
-#[ignore]
 fn cursor_next_and_peek() {
     let mut list = (0..10).collect::<LinkedList<_>>();
     let mut cursor = list.cursor_front();
@@ -215,7 +203,6 @@ fn cursor_next_and_peek() {
 
 // "iterates" via prev() and checks that it visits the right elements
 #[test]
-#[ignore]
 fn cursor_prev_and_peek() {
     let mut list = (0..10).collect::<LinkedList<_>>();
     let mut cursor = list.cursor_back();
@@ -231,7 +218,6 @@ fn cursor_prev_and_peek() {
 
 // removes all elements starting from the middle
 #[test]
-#[ignore]
 fn cursor_take() {
     let mut list = (0..10).collect::<LinkedList<_>>();
     let mut cursor = list.cursor_front();
@@ -254,7 +240,6 @@ fn cursor_take() {
 // may pass for incorrect programs if double frees happen
 // exactly as often as destructor leaks
 #[test]
-#[ignore]
 fn drop_no_double_frees() {
     use std::cell::Cell;
     struct DropCounter<'a>(&'a Cell<usize>);
@@ -279,7 +264,6 @@ fn drop_no_double_frees() {
 }
 
 #[test]
-#[ignore]
 fn drop_large_list() {
     drop((0..2_000_000).collect::<LinkedList<i32>>());
 }
@@ -294,8 +278,8 @@ fn drop_large_list() {
 // pre_implemented.rs for technical reasons.
 
 #[cfg(feature = "advanced")]
+#[allow(dead_code)]
 #[test]
-#[ignore]
 fn advanced_linked_list_is_send_sync() {
     trait AssertSend: Send {}
     trait AssertSync: Sync {}
@@ -307,7 +291,6 @@ fn advanced_linked_list_is_send_sync() {
 #[cfg(feature = "advanced")]
 #[allow(dead_code)]
 #[test]
-#[ignore]
 fn advanced_is_covariant() {
     fn a<'a>(x: LinkedList<&'static str>) -> LinkedList<&'a str> {
         x
@@ -317,3 +300,45 @@ fn advanced_is_covariant() {
         i
     }
 }
+
+#[cfg(feature = "advanced")]
+#[allow(dead_code)]
+#[test]
+fn advanced_read_cursor_is_covariant() {
+    fn a_read_cursor<'a>(c: ReadCursor<'static, &'static str>) -> ReadCursor<'a, &'a str> {
+        c
+    }
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn advanced_cursor_and_iter_are_send_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<Cursor<'static, i32>>();
+    assert_send_sync::<ReadCursor<'static, i32>>();
+    assert_send_sync::<Iter<'static, i32>>();
+    assert_send_sync::<IterMut<'static, i32>>();
+    assert_send_sync::<IntoIter<i32>>();
+}
+
+#[cfg(feature = "advanced")]
+#[test]
+fn advanced_lists_can_be_shared_between_threads() {
+    let list = (0..10).collect::<LinkedList<_>>();
+    let sum: i32 = std::thread::scope(|scope| {
+        let front = scope.spawn(|| list.iter().take(5).sum::<i32>());
+        let back = scope.spawn(|| list.iter().skip(5).sum::<i32>());
+        front.join().unwrap() + back.join().unwrap()
+    });
+    assert_eq!(sum, 45);
+
+    let mut list = std::thread::spawn(move || {
+        let mut list = list;
+        list.push_back(10);
+        list
+    })
+    .join()
+    .unwrap();
+    assert_eq!(list.pop_back(), Some(10));
+}
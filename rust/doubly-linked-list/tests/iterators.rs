@@ -0,0 +1,106 @@
+use doubly_linked_list::*;
+
+#[test]
+fn iter_runs_from_either_end() {
+    let list = (0..5).collect::<LinkedList<_>>();
+    assert!(list.iter().rev().copied().eq((0..5).rev()));
+
+    let mut iter = list.iter();
+    assert_eq!(iter.next(), Some(&0));
+    assert_eq!(iter.next_back(), Some(&4));
+    assert_eq!(iter.next(), Some(&1));
+    assert_eq!(iter.next_back(), Some(&3));
+    assert_eq!(iter.next_back(), Some(&2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn iterators_know_their_length() {
+    let mut list = (0..4).collect::<LinkedList<_>>();
+    let mut iter = list.iter();
+    assert_eq!(iter.len(), 4);
+    iter.next_back();
+    assert_eq!(iter.len(), 3);
+
+    let mut iter_mut = list.iter_mut();
+    iter_mut.next();
+    assert_eq!(iter_mut.len(), 3);
+
+    let mut into_iter = list.into_iter();
+    into_iter.next();
+    into_iter.next_back();
+    assert_eq!(into_iter.len(), 2);
+}
+
+#[test]
+fn iter_mut_changes_elements() {
+    let mut list = (0..5).collect::<LinkedList<_>>();
+    for element in list.iter_mut().rev().take(2) {
+        *element *= 10;
+    }
+    for element in &mut list {
+        *element += 1;
+    }
+    assert!(list.iter().copied().eq([1, 2, 3, 31, 41]));
+}
+
+#[test]
+fn iter_mut_meets_in_the_middle() {
+    let mut list = (0..3).collect::<LinkedList<_>>();
+    let mut iter = list.iter_mut();
+    let front = iter.next().unwrap();
+    let back = iter.next_back().unwrap();
+    let middle = iter.next().unwrap();
+    assert!(iter.next_back().is_none());
+    std::mem::swap(front, back);
+    *middle = 7;
+    assert!(list.iter().copied().eq([2, 7, 0]));
+}
+
+#[test]
+fn into_iter_takes_ownership() {
+    let list = ["a", "b", "c"]
+        .map(String::from)
+        .into_iter()
+        .collect::<LinkedList<_>>();
+    let strings: Vec<String> = list.into_iter().rev().collect();
+    assert_eq!(strings, ["c", "b", "a"]);
+}
+
+#[test]
+fn borrowed_lists_work_in_for_loops() {
+    let list = (1..=4).collect::<LinkedList<_>>();
+    let mut sum = 0;
+    for element in &list {
+        sum += element;
+    }
+    assert_eq!(sum, 10);
+}
+
+#[test]
+fn dropping_a_partly_used_into_iter_drops_the_rest() {
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let list = std::iter::repeat_with(|| Rc::clone(&counter))
+        .take(5)
+        .collect::<LinkedList<_>>();
+    let mut iter = list.into_iter();
+    iter.next();
+    iter.next_back();
+    assert_eq!(Rc::strong_count(&counter), 4);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
+
+#[test]
+fn cloned_iters_are_independent() {
+    let list = (0..3).collect::<LinkedList<_>>();
+    let mut iter = list.iter();
+    iter.next();
+    let copy = iter.clone();
+    iter.next();
+    assert!(copy.copied().eq([1, 2]));
+    assert!(iter.copied().eq([2]));
+}
@@ -0,0 +1,150 @@
+use doubly_linked_list::*;
+
+fn list(range: std::ops::Range<i32>) -> LinkedList<i32> {
+    range.collect()
+}
+
+fn elements(list: &LinkedList<i32>) -> Vec<i32> {
+    list.iter().copied().collect()
+}
+
+#[test]
+fn split_after_moves_the_tail() {
+    let mut front = list(0..6);
+    let back = {
+        let mut cursor = front.cursor_front();
+        cursor.seek_forward(2);
+        cursor.split_after()
+    };
+    assert_eq!(elements(&front), [0, 1, 2]);
+    assert_eq!(elements(&back), [3, 4, 5]);
+    assert_eq!((front.len(), back.len()), (3, 3));
+    assert_eq!(back.iter().next_back(), Some(&5));
+    front.push_back(9);
+    assert_eq!(elements(&front), [0, 1, 2, 9]);
+}
+
+#[test]
+fn split_before_moves_the_head() {
+    let mut back = list(0..6);
+    let front = {
+        let mut cursor = back.cursor_back();
+        cursor.seek_backward(2);
+        let front = cursor.split_before();
+        assert_eq!(cursor.peek_mut(), Some(&mut 3));
+        assert_eq!(cursor.prev(), None);
+        front
+    };
+    assert_eq!(elements(&front), [0, 1, 2]);
+    assert_eq!(elements(&back), [3, 4, 5]);
+    assert_eq!((front.len(), back.len()), (3, 3));
+    back.push_front(9);
+    assert_eq!(elements(&back), [9, 3, 4, 5]);
+}
+
+#[test]
+fn splitting_at_an_end_gives_an_empty_list() {
+    let mut whole = list(0..3);
+    assert!(whole.cursor_back().split_after().is_empty());
+    assert!(whole.cursor_front().split_before().is_empty());
+    assert!(
+        LinkedList::<i32>::new()
+            .cursor_front()
+            .split_after()
+            .is_empty()
+    );
+    assert_eq!(elements(&whole), [0, 1, 2]);
+}
+
+#[test]
+fn splits_after_moving_the_cursor_have_the_right_lengths() {
+    let mut whole = list(0..10);
+    let mut cursor = whole.cursor_front();
+    cursor.seek_forward(6);
+    cursor.insert_before(100);
+    cursor.take();
+    cursor.seek_backward(3);
+    let back = cursor.split_after();
+    let front = cursor.split_before();
+    assert_eq!(elements(&front), [0, 1, 2, 3]);
+    assert_eq!(elements(&back), [5, 100, 7, 8, 9]);
+    assert_eq!((front.len(), back.len(), whole.len()), (4, 5, 1));
+}
+
+#[test]
+fn splice_after_inserts_a_list() {
+    let mut whole = list(0..4);
+    {
+        let mut cursor = whole.cursor_front();
+        cursor.next();
+        cursor.splice_after(list(10..13));
+        assert_eq!(cursor.peek_mut(), Some(&mut 1));
+        assert_eq!(cursor.next(), Some(&mut 10));
+    }
+    assert_eq!(elements(&whole), [0, 1, 10, 11, 12, 2, 3]);
+    assert_eq!(whole.len(), 7);
+    assert!(whole.iter().rev().copied().eq([3, 2, 12, 11, 10, 1, 0]));
+}
+
+#[test]
+fn splice_before_inserts_a_list() {
+    let mut whole = list(0..2);
+    {
+        let mut cursor = whole.cursor_front();
+        cursor.splice_before(list(10..12));
+        assert_eq!(cursor.peek_mut(), Some(&mut 0));
+        let back = cursor.split_after();
+        assert_eq!(elements(&back), [1]);
+    }
+    assert_eq!(elements(&whole), [10, 11, 0]);
+    assert_eq!(whole.pop_front(), Some(10));
+}
+
+#[test]
+fn splicing_into_an_empty_list() {
+    let mut empty = LinkedList::new();
+    {
+        let mut cursor = empty.cursor_front();
+        cursor.splice_before(list(0..3));
+        assert_eq!(cursor.peek_mut(), None);
+        assert_eq!(cursor.prev(), Some(&mut 2));
+        cursor.splice_after(LinkedList::new());
+    }
+    assert_eq!(elements(&empty), [0, 1, 2]);
+    assert_eq!(empty.len(), 3);
+}
+
+#[test]
+fn append_moves_everything_to_the_back() {
+    let mut front = list(0..3);
+    let mut back = list(3..5);
+    front.append(&mut back);
+    assert!(back.is_empty());
+    assert_eq!(elements(&front), [0, 1, 2, 3, 4]);
+    assert_eq!(front.pop_back(), Some(4));
+
+    let mut empty = LinkedList::new();
+    empty.append(&mut front);
+    assert_eq!(elements(&empty), [0, 1, 2, 3]);
+    empty.append(&mut LinkedList::new());
+    assert_eq!(empty.len(), 4);
+}
+
+#[test]
+fn split_and_spliced_lists_drop_every_element() {
+    use std::rc::Rc;
+
+    let counter = Rc::new(());
+    let mut whole = std::iter::repeat_with(|| Rc::clone(&counter))
+        .take(6)
+        .collect::<LinkedList<_>>();
+    let mut cursor = whole.cursor_front();
+    cursor.seek_forward(2);
+    let back = cursor.split_after();
+    let front = cursor.split_before();
+    cursor.splice_after(back);
+    drop(front);
+    assert_eq!(Rc::strong_count(&counter), 5);
+    drop(whole);
+    assert_eq!(Rc::strong_count(&counter), 1);
+}
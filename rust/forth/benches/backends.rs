@@ -0,0 +1,67 @@
+// Compare the speed of the two backends on recursive arithmetic, run
+// from top-level code and through `Forth::call`:
+//
+//     cargo bench --bench backends
+//
+// Fails if the closure backend is not faster than the interpreter.
+
+use std::time::{Duration, Instant};
+
+use forth::*;
+
+const DEFINITIONS: &str = "
+    : fib recursive dup 1 > if dup 1 - fib swap 2 - fib + then ;
+    : countdown dup if 1 - recurse then ;
+    : fib-of ( n -- m ) fib ;
+";
+
+const WORKLOAD: &str = "24 fib drop 1000000 countdown drop";
+
+const RUNS: u32 = 5;
+
+fn forth(backend: Backend) -> Forth {
+    let mut f = Forth::with_options(Options {
+        backend,
+        ..Options::default()
+    });
+    f.eval(DEFINITIONS).unwrap();
+    f
+}
+
+fn time_run(backend: Backend) -> Duration {
+    let mut f = forth(backend);
+    let program = f.compile(WORKLOAD).unwrap();
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        f.run(&program).unwrap();
+    }
+    assert!(f.stack().is_empty());
+    start.elapsed() / RUNS
+}
+
+fn time_call(backend: Backend) -> Duration {
+    let mut f = forth(backend);
+    let start = Instant::now();
+    for _ in 0..RUNS {
+        assert_eq!(f.call("fib-of", &[24]), Ok([46368]));
+    }
+    start.elapsed() / RUNS
+}
+
+fn compare(name: &str, time: fn(Backend) -> Duration) {
+    let interpreter = time(Backend::Interpreter);
+    let closures = time(Backend::Closures);
+    println!("{name}");
+    println!("  interpreter: {interpreter:?} per run");
+    println!("  closures:    {closures:?} per run");
+    println!(
+        "  speedup:     {:.2}x",
+        interpreter.as_secs_f64() / closures.as_secs_f64()
+    );
+    assert!(closures < interpreter, "the closure backend is not faster");
+}
+
+fn main() {
+    compare("run", time_run);
+    compare("call", time_call);
+}
@@ -0,0 +1,106 @@
+#ifndef FORTH_H
+#define FORTH_H
+
+/* Generated by cbindgen from src/ffi.rs. Do not edit. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+#define FORTH_OK 0
+
+#define FORTH_DIVISION_BY_ZERO 1
+
+#define FORTH_STACK_UNDERFLOW 2
+
+#define FORTH_UNKNOWN_WORD 3
+
+#define FORTH_INVALID_WORD 4
+
+#define FORTH_ABORTED 5
+
+#define FORTH_STACK_EFFECT_MISMATCH 6
+
+#define FORTH_RETURN_STACK_OVERFLOW 7
+
+#define FORTH_UNKNOWN_FILE 8
+
+#define FORTH_INVALID_ADDRESS 9
+
+#define FORTH_DATA_SPACE_OVERFLOW 10
+
+#define FORTH_OVERFLOW 11
+
+#define FORTH_INVALID_WIDTH 12
+
+/**
+ * A null pointer, or source that is not UTF-8, was passed in.
+ */
+#define FORTH_INVALID_ARGUMENT 100
+
+/**
+ * An interpreter owned by C code, with the message of its last error.
+ */
+typedef struct ForthHandle ForthHandle;
+
+typedef int32_t forth_value_t;
+
+/**
+ * Create an interpreter. Free it with `forth_free`.
+ */
+ForthHandle *forth_new(void);
+
+/**
+ * Free an interpreter made by `forth_new`. Null is ignored.
+ *
+ * # Safety
+ *
+ * `handle` must be null or come from `forth_new`, and must not be used
+ * again.
+ */
+void forth_free(ForthHandle *handle);
+
+/**
+ * Evaluate NUL-terminated UTF-8 source, returning `FORTH_OK` or an error
+ * code. The message for the error is then available from
+ * `forth_error_message`.
+ *
+ * # Safety
+ *
+ * `handle` must come from `forth_new`, and `source` must be null or point
+ * to a NUL-terminated string.
+ */
+int32_t forth_eval(ForthHandle *handle, const char *source);
+
+/**
+ * The number of cells on the data stack.
+ *
+ * # Safety
+ *
+ * `handle` must be null or come from `forth_new`.
+ */
+size_t forth_stack_len(const ForthHandle *handle);
+
+/**
+ * The cells of the data stack, bottom first, valid until the interpreter
+ * is next used. Null if the stack is empty.
+ *
+ * # Safety
+ *
+ * `handle` must be null or come from `forth_new`.
+ */
+const forth_value_t *forth_stack(const ForthHandle *handle);
+
+/**
+ * The message of the error returned by the last `forth_eval`, valid until
+ * the interpreter is next used. Null if it succeeded.
+ *
+ * # Safety
+ *
+ * `handle` must be null or come from `forth_new`.
+ */
+const char *forth_error_message(const ForthHandle *handle);
+
+#endif /* FORTH_H */
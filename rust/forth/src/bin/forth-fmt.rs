@@ -0,0 +1,50 @@
+//! Format or lint Forth source files.
+//!
+//! `forth-fmt FILE...` prints each file formatted, and `forth-fmt --lint
+//! FILE...` prints what the linter finds in them. Without files, standard
+//! input is read.
+
+use std::io::{self, Read};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args: Vec<String> = std::env::args().skip(1).collect();
+    let linting = args.first().is_some_and(|arg| arg == "--lint");
+    if linting {
+        args.remove(0);
+    }
+    if args.iter().any(|arg| arg.starts_with('-') && arg != "-") {
+        eprintln!("usage: forth-fmt [--lint] [FILE]...");
+        return ExitCode::from(2);
+    }
+    if args.is_empty() {
+        args.push("-".to_string());
+    }
+
+    let mut status = ExitCode::SUCCESS;
+    for path in &args {
+        let source = if path == "-" {
+            let mut source = String::new();
+            io::stdin().read_to_string(&mut source).map(|_| source)
+        } else {
+            std::fs::read_to_string(path)
+        };
+        let source = match source {
+            Ok(source) => source,
+            Err(error) => {
+                eprintln!("{path}: {error}");
+                status = ExitCode::FAILURE;
+                continue;
+            }
+        };
+        if linting {
+            for lint in forth::lint(&source) {
+                println!("{path}:{lint}");
+                status = ExitCode::FAILURE;
+            }
+        } else {
+            print!("{}", forth::format(&source));
+        }
+    }
+    status
+}
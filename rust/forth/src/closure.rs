@@ -0,0 +1,90 @@
+use std::sync::Arc;
+
+use crate::{Error, Forth, Op, Result, Value, Word, flag};
+
+// One instruction of a definition compiled for `Backend::Closures`.
+pub(crate) type Code = Box<dyn Fn(&mut Forth) -> Result + Send + Sync>;
+
+// Compile a body into closures, one per op, so that branch targets and
+// return addresses mean the same as in the interpreter.
+pub(super) fn compile(body: &[Op]) -> Box<[Code]> {
+    body.iter().map(compile_op).collect()
+}
+
+fn compile_op(op: &Op) -> Code {
+    match op {
+        Op::Add => Box::new(|forth| forth.calculate(Value::checked_add)),
+        Op::Sub => Box::new(|forth| forth.calculate(Value::checked_sub)),
+        Op::Mul => Box::new(|forth| forth.calculate(Value::checked_mul)),
+        Op::Div => Box::new(|forth| forth.calculate(Value::checked_div)),
+        Op::Equal => Box::new(|forth| forth.calculate(|a, b| Some(flag(a == b)))),
+        Op::Less => Box::new(|forth| forth.calculate(|a, b| Some(flag(a < b)))),
+        Op::Greater => Box::new(|forth| forth.calculate(|a, b| Some(flag(a > b)))),
+        Op::Dup => Box::new(Forth::dup),
+        Op::Drop => Box::new(Forth::drop),
+        Op::Swap => Box::new(|forth| forth.swap_over(false)),
+        Op::Over => Box::new(|forth| forth.swap_over(true)),
+        &Op::Push(val) => Box::new(move |forth| {
+            forth.data.push(val);
+            Ok(())
+        }),
+        Op::Word(word) => {
+            let word = Arc::clone(word);
+            Box::new(move |forth| forth.enter(&word))
+        }
+        Op::Recurse => Box::new(Forth::recurse),
+        &Op::Branch(target) => Box::new(move |forth| {
+            forth.jump(target);
+            Ok(())
+        }),
+        &Op::BranchIfZero(target) => Box::new(move |forth| {
+            if forth.data.pop().ok_or(Error::StackUnderflow)? == 0 {
+                forth.jump(target);
+            }
+            Ok(())
+        }),
+        &Op::Local(slot, _) => Box::new(move |forth| {
+            let val = forth.locals[forth.locals_base() + slot];
+            forth.data.push(val);
+            Ok(())
+        }),
+        op => {
+            let op = op.clone();
+            Box::new(move |forth| forth.execute_primitive(&op))
+        }
+    }
+}
+
+impl Forth {
+    // The closure backend's inner loop, used when no tracer needs to see
+    // each instruction. Words compiled by the interpreter backend, such as
+    // those of a frozen dictionary, fall back to `step`.
+    pub(super) fn execute_compiled(&mut self, base: usize) -> Result {
+        // Held across iterations so that most steps do not touch the
+        // reference count.
+        let mut word: Option<Arc<Word>> = None;
+        while self.frames.len() > base && !self.pausing {
+            let top = self.frames.len() - 1;
+            let frame = &mut self.frames[top];
+            if !word
+                .as_ref()
+                .is_some_and(|word| Arc::ptr_eq(word, &frame.word))
+            {
+                word = Some(Arc::clone(&frame.word));
+            }
+            let current = word.as_deref().expect("set above");
+            let ip = frame.ip;
+            if ip == current.body.len() {
+                let frame = self.frames.pop().expect("frames above base");
+                self.locals.truncate(frame.locals);
+                continue;
+            }
+            frame.ip += 1;
+            match &current.code {
+                Some(code) => code[ip](self)?,
+                None => self.step(&current.body[ip])?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,105 @@
+use crate::{Error, Forth, Frame, Instruction, Op, Result, Value};
+
+/// Hooks called by the interpreter as it executes.
+///
+/// `before` and `after` see every primitive; `on_break` is called when a
+/// breakpoint is reached or while single-stepping, and decides how to resume.
+pub trait Tracer {
+    fn before(&mut self, _event: &Event<'_>) {}
+
+    fn after(&mut self, _event: &Event<'_>, _result: &Result) {}
+
+    fn on_break(&mut self, _event: &Event<'_>) -> Resume {
+        Resume::Continue
+    }
+}
+
+/// The interpreter state around one instruction.
+#[derive(Debug)]
+pub struct Event<'a> {
+    pub instruction: Instruction<'a>,
+    /// The user word whose body is executing, or `None` at the top level.
+    pub word: Option<&'a str>,
+    /// How many user words are active on the return stack.
+    pub depth: usize,
+    pub stack: &'a [Value],
+}
+
+impl<'a> Event<'a> {
+    fn new(op: &'a Op, frames: &'a [Frame], stack: &'a [Value]) -> Self {
+        Event {
+            instruction: op.instruction(),
+            word: frames.last().map(|frame| &*frame.word.name),
+            depth: frames.len(),
+            stack,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resume {
+    /// Run until the next breakpoint.
+    Continue,
+    /// Break again before the next instruction.
+    Step,
+    /// Stop executing and fail with [`Error::Aborted`].
+    Abort,
+}
+
+impl Forth {
+    pub fn set_tracer(&mut self, tracer: impl Tracer + 'static) {
+        self.tracer = Some(Box::new(tracer));
+    }
+
+    pub fn clear_tracer(&mut self) -> Option<Box<dyn Tracer>> {
+        self.tracer.take()
+    }
+
+    /// Break whenever the user word `name` is about to be entered.
+    pub fn set_breakpoint(&mut self, name: &str) {
+        self.breakpoints.insert(self.fold_name(name));
+    }
+
+    pub fn clear_breakpoint(&mut self, name: &str) -> bool {
+        self.breakpoints.remove(&self.fold_name(name))
+    }
+
+    /// Break before every instruction until the tracer resumes with
+    /// [`Resume::Continue`].
+    pub fn set_single_step(&mut self, stepping: bool) {
+        self.stepping = stepping;
+    }
+
+    pub(super) fn check_break(&mut self, op: &Op) -> Result {
+        let callee = match op {
+            Op::Word(word) => Some(word),
+            Op::Recurse => self.frames.last().map(|frame| &frame.word),
+            _ => None,
+        };
+        let hit =
+            self.stepping || callee.is_some_and(|word| self.breakpoints.contains(&*word.name));
+        let Some(tracer) = self.tracer.as_mut().filter(|_| hit) else {
+            return Ok(());
+        };
+
+        let event = Event::new(op, &self.frames, &self.data);
+        match tracer.on_break(&event) {
+            Resume::Continue => self.stepping = false,
+            Resume::Step => self.stepping = true,
+            Resume::Abort => return Err(Error::Aborted),
+        }
+        Ok(())
+    }
+
+    pub(super) fn trace_before(&mut self, op: &Op) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.before(&Event::new(op, &self.frames, &self.data));
+        }
+    }
+
+    pub(super) fn trace_after(&mut self, op: &Op, result: &Result) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.after(&Event::new(op, &self.frames, &self.data), result);
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use crate::intern::Symbol;
+use crate::{Error, Word};
+
+// The wordlist of the FORTH vocabulary, which user words go into by default.
+pub(crate) const FORTH: usize = 0;
+
+/// User definitions, split into wordlists that are searched in order.
+#[derive(Clone)]
+pub(crate) struct Dictionary {
+    wordlists: Vec<Wordlist>,
+    // The last wordlist is searched first.
+    search_order: Vec<usize>,
+    // Where new definitions go.
+    current: usize,
+    // Every definition in the order it was made, so it can be undone.
+    log: Vec<Entry>,
+}
+
+#[derive(Clone)]
+struct Wordlist {
+    name: Arc<str>,
+    words: HashMap<Symbol, Arc<Word>>,
+}
+
+#[derive(Clone)]
+struct Entry {
+    wordlist: usize,
+    symbol: Symbol,
+    // The definition this one hid, put back if this one is forgotten.
+    shadowed: Option<Arc<Word>>,
+}
+
+/// A saved state of the dictionary, restored by running a `MARKER` word.
+pub(crate) struct Mark {
+    log: usize,
+    search_order: Vec<usize>,
+    current: usize,
+}
+
+impl Dictionary {
+    pub(crate) fn new() -> Self {
+        Dictionary {
+            wordlists: vec![Wordlist {
+                name: "forth".into(),
+                words: HashMap::new(),
+            }],
+            search_order: vec![FORTH],
+            current: FORTH,
+            log: Vec::new(),
+        }
+    }
+
+    pub(crate) fn find(&self, symbol: Symbol) -> Option<&Arc<Word>> {
+        self.search_order
+            .iter()
+            .rev()
+            .find_map(|&wordlist| self.wordlists[wordlist].words.get(&symbol))
+    }
+
+    pub(crate) fn define(&mut self, symbol: Symbol, word: Arc<Word>) {
+        let shadowed = self.wordlists[self.current].words.insert(symbol, word);
+        self.log.push(Entry {
+            wordlist: self.current,
+            symbol,
+            shadowed,
+        });
+    }
+
+    // The names in the first wordlist of the search order, newest first.
+    pub(crate) fn words(&self) -> impl Iterator<Item = Symbol> + '_ {
+        let first = self.search_order.last().copied();
+        let mut seen = HashSet::new();
+        self.log
+            .iter()
+            .rev()
+            .filter(move |entry| Some(entry.wordlist) == first)
+            .map(|entry| entry.symbol)
+            .filter(move |&symbol| seen.insert(symbol))
+    }
+
+    pub(crate) fn add_wordlist(&mut self, name: Arc<str>) -> usize {
+        self.wordlists.push(Wordlist {
+            name,
+            words: HashMap::new(),
+        });
+        self.wordlists.len() - 1
+    }
+
+    pub(crate) fn mark(&self) -> Mark {
+        Mark {
+            log: self.log.len(),
+            search_order: self.search_order.clone(),
+            current: self.current,
+        }
+    }
+
+    pub(crate) fn restore(&mut self, mark: &Mark) {
+        self.rollback(mark.log);
+        self.search_order.clone_from(&mark.search_order);
+        self.current = mark.current;
+    }
+
+    // Remove the visible definition of `symbol` and everything defined
+    // after it.
+    pub(crate) fn forget(&mut self, symbol: Symbol) -> bool {
+        let Some(&wordlist) = self
+            .search_order
+            .iter()
+            .rev()
+            .find(|&&wordlist| self.wordlists[wordlist].words.contains_key(&symbol))
+        else {
+            return false;
+        };
+        let position = self
+            .log
+            .iter()
+            .rposition(|entry| entry.wordlist == wordlist && entry.symbol == symbol)
+            .expect("every definition is logged");
+        self.rollback(position);
+        true
+    }
+
+    fn rollback(&mut self, len: usize) {
+        while self.log.len() > len {
+            let entry = self.log.pop().expect("log is longer than len");
+            let words = &mut self.wordlists[entry.wordlist].words;
+            match entry.shadowed {
+                Some(word) => words.insert(entry.symbol, word),
+                None => words.remove(&entry.symbol),
+            };
+        }
+    }
+
+    // Replace the first wordlist in the search order.
+    pub(crate) fn set_context(&mut self, wordlist: usize) {
+        match self.search_order.last_mut() {
+            Some(first) => *first = wordlist,
+            None => self.search_order.push(wordlist),
+        }
+    }
+
+    pub(crate) fn also(&mut self) -> Result<(), Error> {
+        let first = *self.search_order.last().ok_or(Error::InvalidWord)?;
+        self.search_order.push(first);
+        Ok(())
+    }
+
+    pub(crate) fn only(&mut self) {
+        self.search_order = vec![FORTH];
+    }
+
+    pub(crate) fn previous(&mut self) -> Result<(), Error> {
+        self.search_order.pop().ok_or(Error::InvalidWord)?;
+        Ok(())
+    }
+
+    pub(crate) fn definitions(&mut self) -> Result<(), Error> {
+        self.current = *self.search_order.last().ok_or(Error::InvalidWord)?;
+        Ok(())
+    }
+
+    // The search order, first searched first, then the wordlist that new
+    // definitions go into, laid out as gforth's ORDER does.
+    pub(crate) fn order(&self) -> String {
+        let order: Vec<&str> = self
+            .search_order
+            .iter()
+            .rev()
+            .map(|&wordlist| &*self.wordlists[wordlist].name)
+            .collect();
+        format!(
+            "{}    {}",
+            order.join(" "),
+            self.wordlists[self.current].name
+        )
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{Error, Forth, Op, Result, Value, flag};
+
+/// A double-cell number, kept on the stack as two cells with the high
+/// cell on top.
+pub type Double = i64;
+
+// The double made of the cells `low high`.
+pub(super) fn join(low: Value, high: Value) -> Double {
+    (Double::from(high) << 32) | Double::from(low as u32)
+}
+
+// The cells `low high` of a double.
+pub(super) fn split(double: Double) -> [Value; 2] {
+    [double as Value, (double >> 32) as Value]
+}
+
+impl Forth {
+    // The double-cell words. Like the string words, each checks its
+    // arguments before taking them, so a failure leaves the stack alone.
+    pub(super) fn execute_double(&mut self, op: &Op) -> Result {
+        match op {
+            Op::PushDouble(double) => self.data.extend(split(*double)),
+            Op::DAdd | Op::DSub => {
+                let [low1, high1, low2, high2] = self.peek()?;
+                let (a, b) = (join(low1, high1), join(low2, high2));
+                let result = match op {
+                    Op::DAdd => a.checked_add(b),
+                    _ => a.checked_sub(b),
+                };
+                self.replace(4, &split(result.ok_or(Error::Overflow)?));
+            }
+            Op::DDot => {
+                let [low, high] = self.peek()?;
+                let text = format!("{} ", join(low, high));
+                self.output.push_str(&text);
+                self.replace(2, &[]);
+            }
+            Op::MStar => {
+                let [a, b] = self.peek()?;
+                self.replace(2, &split(Double::from(a) * Double::from(b)));
+            }
+            Op::UMStar => {
+                let [a, b] = self.peek()?;
+                let product = u64::from(a as u32) * u64::from(b as u32);
+                self.replace(2, &split(product as Double));
+            }
+            Op::UMSlashMod => {
+                let [low, high, divisor] = self.peek()?;
+                let dividend = join(low, high) as u64;
+                let divisor = u64::from(divisor as u32);
+                if divisor == 0 {
+                    return Err(Error::DivisionByZero);
+                }
+                let quotient = u32::try_from(dividend / divisor).map_err(|_| Error::Overflow)?;
+                let remainder = (dividend % divisor) as u32;
+                self.replace(3, &[remainder as Value, quotient as Value]);
+            }
+            Op::SToD => {
+                let [n] = self.peek()?;
+                self.replace(1, &split(Double::from(n)));
+            }
+            Op::DToS => {
+                let [low, high] = self.peek()?;
+                let n = Value::try_from(join(low, high)).map_err(|_| Error::Overflow)?;
+                self.replace(2, &[n]);
+            }
+            Op::DEqual | Op::DLess | Op::DGreater => {
+                let [low1, high1, low2, high2] = self.peek()?;
+                let (a, b) = (join(low1, high1), join(low2, high2));
+                let result = match op {
+                    Op::DEqual => a == b,
+                    Op::DLess => a < b,
+                    _ => a > b,
+                };
+                self.replace(4, &[flag(result)]);
+            }
+            Op::DZeroEqual | Op::DZeroLess => {
+                let [low, high] = self.peek()?;
+                let double = join(low, high);
+                let result = match op {
+                    Op::DZeroEqual => double == 0,
+                    _ => double < 0,
+                };
+                self.replace(2, &[flag(result)]);
+            }
+            _ => unreachable!("not a double-cell word"),
+        }
+        Ok(())
+    }
+}
+
+// A double-cell literal is a number with a trailing `.`, as in `123.`.
+pub(super) fn parse(token: &str) -> Option<Double> {
+    token.strip_suffix('.')?.parse().ok()
+}
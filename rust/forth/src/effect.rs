@@ -0,0 +1,192 @@
+use crate::Op;
+
+/// How many cells a word takes from and leaves on the data stack,
+/// as in the Forth comment `( a b -- c )`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StackEffect {
+    pub inputs: usize,
+    pub outputs: usize,
+}
+
+impl StackEffect {
+    pub const fn new(inputs: usize, outputs: usize) -> Self {
+        StackEffect { inputs, outputs }
+    }
+
+    /// The effect of running `self` and then `next`.
+    pub fn then(self, next: StackEffect) -> StackEffect {
+        if self.outputs >= next.inputs {
+            StackEffect::new(self.inputs, self.outputs - next.inputs + next.outputs)
+        } else {
+            StackEffect::new(self.inputs + next.inputs - self.outputs, next.outputs)
+        }
+    }
+
+    // The combined effect of a body, or `None` if any part of it is not
+    // statically known or its branches leave different depths.
+    pub(super) fn infer(body: &[Op]) -> Option<StackEffect> {
+        let flow: Vec<_> = body
+            .iter()
+            .map(|op| match op {
+                Op::Branch(target) => Flow::Jump(*target),
+                Op::BranchIfZero(target) => Flow::JumpIfZero(*target),
+                op => Flow::Run(op.effect()),
+            })
+            .collect();
+        StackEffect::of_paths(&paths(&flow))
+    }
+
+    // The effect of a body whose every path is known and ends at the same
+    // depth; it takes as many cells as the deepest path reaches.
+    pub(super) fn of_paths(paths: &[Path]) -> Option<StackEffect> {
+        let depth = paths.first()?.depth;
+        if !paths.iter().all(|path| path.known && path.depth == depth) {
+            return None;
+        }
+        let low = paths.iter().map(|path| path.low).min()?;
+        Some(StackEffect::new(-low as usize, (depth - low) as usize))
+    }
+
+    // Read a declaration from the text of a `( ... )` comment. Comments
+    // without a `--` separator are not declarations.
+    pub(super) fn parse(comment: &str) -> Option<StackEffect> {
+        let items: Vec<_> = comment.split_whitespace().collect();
+        let separator = items.iter().position(|&item| item == "--")?;
+        Some(StackEffect::new(separator, items.len() - separator - 1))
+    }
+
+    // Whether a body with the `inferred` effect honours this declaration:
+    // it must not reach below the declared inputs, and must change the
+    // stack depth by the declared amount.
+    pub(super) fn admits(self, inferred: StackEffect) -> bool {
+        inferred.inputs <= self.inputs
+            && inferred.outputs + self.inputs == self.outputs + inferred.inputs
+    }
+}
+
+// One step of a body for stack effect analysis, whether compiled ops or
+// the linter's reading of source.
+pub(super) enum Flow {
+    // Something with this effect, or an unknown one.
+    Run(Option<StackEffect>),
+    // Go on from this index.
+    Jump(usize),
+    // Take a flag, and go on from this index if it is zero.
+    JumpIfZero(usize),
+}
+
+// The stack depth along one path through a body, relative to the depth it
+// started at, and the lowest depth reached. Once something with an unknown
+// effect runs, the depth is no longer known.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(super) struct Path {
+    pub(super) depth: isize,
+    pub(super) low: isize,
+    pub(super) known: bool,
+}
+
+// The distinct paths from the start of `flow` to its end. Jumps go forward,
+// as `IF`, `ELSE` and `THEN` compile; a path that jumps back is no longer
+// known, and is followed on to the next step.
+pub(super) fn paths(flow: &[Flow]) -> Vec<Path> {
+    // The paths arriving at each step, and at the end.
+    let mut arriving = vec![Vec::new(); flow.len() + 1];
+    arriving[0].push(Path {
+        depth: 0,
+        low: 0,
+        known: true,
+    });
+    for (index, step) in flow.iter().enumerate() {
+        let mut paths = std::mem::take(&mut arriving[index]);
+        paths.sort();
+        paths.dedup();
+        match *step {
+            Flow::Run(effect) => apply(&mut paths, effect),
+            Flow::Jump(target) | Flow::JumpIfZero(target) if target <= index => {
+                apply(&mut paths, None);
+            }
+            Flow::Jump(target) => arriving[target.min(flow.len())].append(&mut paths),
+            Flow::JumpIfZero(target) => {
+                apply(&mut paths, Some(StackEffect::new(1, 0)));
+                arriving[target.min(flow.len())].extend(paths.iter().copied());
+            }
+        }
+        arriving[index + 1].extend(paths);
+    }
+    let mut paths = arriving.pop().unwrap_or_default();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+fn apply(paths: &mut [Path], effect: Option<StackEffect>) {
+    for path in paths.iter_mut().filter(|path| path.known) {
+        match effect {
+            Some(effect) => {
+                path.depth -= effect.inputs as isize;
+                path.low = path.low.min(path.depth);
+                path.depth += effect.outputs as isize;
+            }
+            None => path.known = false,
+        }
+    }
+}
+
+impl Op {
+    // How many cells from the top of the stack a primitive reads or takes.
+    pub(super) fn reads(&self) -> usize {
+        match self {
+            Op::BranchIfZero(_) => 1,
+            op => op.effect().map_or(0, |effect| effect.inputs),
+        }
+    }
+
+    pub(super) fn effect(&self) -> Option<StackEffect> {
+        match self {
+            Op::Add | Op::Sub | Op::Mul | Op::Div => Some(StackEffect::new(2, 1)),
+            Op::Equal | Op::Less | Op::Greater => Some(StackEffect::new(2, 1)),
+            Op::Dup => Some(StackEffect::new(1, 2)),
+            Op::Drop => Some(StackEffect::new(1, 0)),
+            Op::Swap => Some(StackEffect::new(2, 2)),
+            Op::Over => Some(StackEffect::new(2, 3)),
+            Op::Words
+            | Op::Pause
+            | Op::Context(..)
+            | Op::Also
+            | Op::Only
+            | Op::Previous
+            | Op::Definitions
+            | Op::Order
+            | Op::Marker(_) => Some(StackEffect::new(0, 0)),
+            Op::Push(_) => Some(StackEffect::new(0, 1)),
+            Op::String(..) | Op::PushDouble(_) => Some(StackEffect::new(0, 2)),
+            Op::DAdd | Op::DSub => Some(StackEffect::new(4, 2)),
+            Op::DEqual | Op::DLess | Op::DGreater => Some(StackEffect::new(4, 1)),
+            Op::DDot => Some(StackEffect::new(2, 0)),
+            Op::DZeroEqual | Op::DZeroLess | Op::DToS => Some(StackEffect::new(2, 1)),
+            Op::MStar | Op::UMStar => Some(StackEffect::new(2, 2)),
+            Op::UMSlashMod => Some(StackEffect::new(3, 2)),
+            Op::SToD => Some(StackEffect::new(1, 2)),
+            Op::LessNumberSign => Some(StackEffect::new(0, 0)),
+            Op::NumberSign | Op::NumberSignS | Op::NumberSignGreater => {
+                Some(StackEffect::new(2, 2))
+            }
+            Op::Hold | Op::Sign | Op::Dot | Op::UDot => Some(StackEffect::new(1, 0)),
+            Op::DotR | Op::UDotR => Some(StackEffect::new(2, 0)),
+            Op::Type | Op::CStore => Some(StackEffect::new(2, 0)),
+            Op::Count => Some(StackEffect::new(1, 2)),
+            Op::CFetch => Some(StackEffect::new(1, 1)),
+            Op::Compare => Some(StackEffect::new(4, 1)),
+            Op::Search => Some(StackEffect::new(4, 3)),
+            Op::SlashString => Some(StackEffect::new(3, 2)),
+            Op::CMove => Some(StackEffect::new(3, 0)),
+            Op::Word(word) => word.effect,
+            // Branches are followed by `StackEffect::infer`, not here.
+            Op::Recurse | Op::Branch(_) | Op::BranchIfZero(_) => None,
+            Op::Locals(locals) => Some(StackEffect::new(locals.args, 0)),
+            Op::Local(..) => Some(StackEffect::new(0, 1)),
+            Op::ToLocal(..) => Some(StackEffect::new(1, 0)),
+            Op::Need(depth) | Op::NeedOrDrop(depth) => Some(StackEffect::new(*depth, *depth)),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! A C interface to the interpreter, declared in `include/forth.h`.
+//!
+//! The header is generated with
+//! `cbindgen --config cbindgen.toml --output include/forth.h`.
+
+use std::ffi::{CStr, CString, c_char};
+use std::ptr;
+
+use crate::{Error, Forth, Value};
+
+pub const FORTH_OK: i32 = 0;
+pub const FORTH_DIVISION_BY_ZERO: i32 = 1;
+pub const FORTH_STACK_UNDERFLOW: i32 = 2;
+pub const FORTH_UNKNOWN_WORD: i32 = 3;
+pub const FORTH_INVALID_WORD: i32 = 4;
+pub const FORTH_ABORTED: i32 = 5;
+pub const FORTH_STACK_EFFECT_MISMATCH: i32 = 6;
+pub const FORTH_RETURN_STACK_OVERFLOW: i32 = 7;
+pub const FORTH_UNKNOWN_FILE: i32 = 8;
+pub const FORTH_INVALID_ADDRESS: i32 = 9;
+pub const FORTH_DATA_SPACE_OVERFLOW: i32 = 10;
+pub const FORTH_OVERFLOW: i32 = 11;
+pub const FORTH_INVALID_WIDTH: i32 = 12;
+/// A null pointer, or source that is not UTF-8, was passed in.
+pub const FORTH_INVALID_ARGUMENT: i32 = 100;
+
+/// An interpreter owned by C code, with the message of its last error.
+pub struct ForthHandle {
+    forth: Forth,
+    error: Option<CString>,
+}
+
+impl Error {
+    /// The code reported for this error through the C interface. Errors in
+    /// included files report the code of the error that caused them.
+    pub fn code(&self) -> i32 {
+        match self.root() {
+            Error::DivisionByZero => FORTH_DIVISION_BY_ZERO,
+            Error::StackUnderflow => FORTH_STACK_UNDERFLOW,
+            Error::UnknownWord => FORTH_UNKNOWN_WORD,
+            Error::InvalidWord => FORTH_INVALID_WORD,
+            Error::Aborted => FORTH_ABORTED,
+            Error::StackEffectMismatch => FORTH_STACK_EFFECT_MISMATCH,
+            Error::ReturnStackOverflow => FORTH_RETURN_STACK_OVERFLOW,
+            Error::UnknownFile => FORTH_UNKNOWN_FILE,
+            Error::InvalidAddress => FORTH_INVALID_ADDRESS,
+            Error::DataSpaceOverflow => FORTH_DATA_SPACE_OVERFLOW,
+            Error::Overflow => FORTH_OVERFLOW,
+            Error::InvalidWidth => FORTH_INVALID_WIDTH,
+            Error::Included { .. } => unreachable!("root looks through included files"),
+        }
+    }
+}
+
+/// Create an interpreter. Free it with `forth_free`.
+#[unsafe(no_mangle)]
+pub extern "C" fn forth_new() -> *mut ForthHandle {
+    Box::into_raw(Box::new(ForthHandle {
+        forth: Forth::new(),
+        error: None,
+    }))
+}
+
+/// Free an interpreter made by `forth_new`. Null is ignored.
+///
+/// # Safety
+///
+/// `handle` must be null or come from `forth_new`, and must not be used
+/// again.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn forth_free(handle: *mut ForthHandle) {
+    if !handle.is_null() {
+        drop(unsafe { Box::from_raw(handle) });
+    }
+}
+
+/// Evaluate NUL-terminated UTF-8 source, returning `FORTH_OK` or an error
+/// code. The message for the error is then available from
+/// `forth_error_message`.
+///
+/// # Safety
+///
+/// `handle` must come from `forth_new`, and `source` must be null or point
+/// to a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn forth_eval(handle: *mut ForthHandle, source: *const c_char) -> i32 {
+    let Some(handle) = (unsafe { handle.as_mut() }) else {
+        return FORTH_INVALID_ARGUMENT;
+    };
+    handle.error = None;
+    if source.is_null() {
+        return handle.fail(FORTH_INVALID_ARGUMENT, "source is null");
+    }
+    let Ok(source) = unsafe { CStr::from_ptr(source) }.to_str() else {
+        return handle.fail(FORTH_INVALID_ARGUMENT, "source is not UTF-8");
+    };
+    match handle.forth.eval(source) {
+        Ok(()) => FORTH_OK,
+        Err(error) => handle.fail(error.code(), &error.to_string()),
+    }
+}
+
+/// The number of cells on the data stack.
+///
+/// # Safety
+///
+/// `handle` must be null or come from `forth_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn forth_stack_len(handle: *const ForthHandle) -> usize {
+    unsafe { handle.as_ref() }.map_or(0, |handle| handle.forth.stack().len())
+}
+
+/// The cells of the data stack, bottom first, valid until the interpreter
+/// is next used. Null if the stack is empty.
+///
+/// # Safety
+///
+/// `handle` must be null or come from `forth_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn forth_stack(handle: *const ForthHandle) -> *const Value {
+    match unsafe { handle.as_ref() } {
+        Some(handle) if !handle.forth.stack().is_empty() => handle.forth.stack().as_ptr(),
+        _ => ptr::null(),
+    }
+}
+
+/// The message of the error returned by the last `forth_eval`, valid until
+/// the interpreter is next used. Null if it succeeded.
+///
+/// # Safety
+///
+/// `handle` must be null or come from `forth_new`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn forth_error_message(handle: *const ForthHandle) -> *const c_char {
+    unsafe { handle.as_ref() }
+        .and_then(|handle| handle.error.as_deref())
+        .map_or(ptr::null(), CStr::as_ptr)
+}
+
+impl ForthHandle {
+    fn fail(&mut self, code: i32, message: &str) -> i32 {
+        // Messages come from file names and error text, which cannot hold
+        // a NUL unless a file name does.
+        self.error = Some(CString::new(message.replace('\0', "")).expect("NULs removed"));
+        code
+    }
+}
@@ -0,0 +1,162 @@
+use std::collections::HashMap;
+
+use crate::is_reserved;
+use crate::syntax::{self, Definition, Item, Token, is};
+
+/// Lay out Forth source consistently.
+///
+/// Each definition starts on its own line. One without `IF` stays on that
+/// line; otherwise its body is indented by two spaces per level of
+/// `IF ... ELSE ... THEN`. Built-in words are written in lowercase, and
+/// user words as they were first defined. Top-level code keeps its line
+/// breaks, with runs of blank lines reduced to one.
+///
+/// Names are matched ignoring case, as with the default [`Options`].
+///
+/// [`Options`]: crate::Options
+pub fn format(source: &str) -> String {
+    let items = syntax::parse(source);
+    let mut spellings = HashMap::new();
+    for spanned in &items {
+        if let Some(name) = spanned.item.defined_name() {
+            spellings.entry(name.to_lowercase()).or_insert(name);
+        }
+    }
+    let formatter = Formatter { spellings };
+
+    let mut out = String::new();
+    let mut line_open = false;
+    let mut previous_end = None;
+    for spanned in &items {
+        let gap = previous_end.map_or(0, |end| spanned.start - end);
+        let definition = matches!(spanned.item, Item::Definition(_));
+        if line_open && (gap > 0 || definition) {
+            out.push('\n');
+            line_open = false;
+        }
+        if gap > 1 {
+            out.push('\n');
+        }
+        match &spanned.item {
+            Item::Definition(definition) => {
+                for line in formatter.definition(definition) {
+                    out.push_str(&line);
+                    out.push('\n');
+                }
+            }
+            Item::Token(token) => {
+                if line_open {
+                    out.push(' ');
+                }
+                out.push_str(&formatter.token(token));
+                line_open = true;
+            }
+        }
+        previous_end = Some(spanned.end);
+    }
+    if line_open {
+        out.push('\n');
+    }
+    out
+}
+
+struct Formatter<'a> {
+    // The first spelling of each user word, by its lowercase name.
+    spellings: HashMap<String, &'a str>,
+}
+
+impl Formatter<'_> {
+    fn spell(&self, word: &str) -> String {
+        let lower = word.to_lowercase();
+        if is_reserved(&lower) {
+            return lower;
+        }
+        match self.spellings.get(&lower) {
+            Some(spelling) => spelling.to_string(),
+            None => word.to_string(),
+        }
+    }
+
+    fn token(&self, token: &Token<'_>) -> String {
+        match token {
+            Token::Word(word) => self.spell(word),
+            Token::Comment(text) => format!("( {text})"),
+            Token::String(word, text) => format!("{} {text}\"", word.to_lowercase()),
+            Token::Locals(words) if words.is_empty() => "{: :}".to_string(),
+            Token::Locals(words) => format!("{{: {} :}}", words.join(" ")),
+            Token::Parsing(word, name) if is(word, "see") || is(word, "forget") => {
+                format!("{} {}", word.to_lowercase(), self.spell(name))
+            }
+            Token::Parsing(word, name) => format!("{} {name}", word.to_lowercase()),
+        }
+    }
+
+    fn definition(&self, definition: &Definition<'_>) -> Vec<String> {
+        let mut header = format!(": {}", self.spell(definition.name));
+        let mut body = &definition.body[..];
+        if let [comment @ Token::Comment(_), rest @ ..] = body {
+            header.push(' ');
+            header.push_str(&self.token(comment));
+            body = rest;
+        }
+        let end = if definition.closed { ";" } else { "" };
+
+        let branches = body
+            .iter()
+            .any(|token| matches!(token, Token::Word(word) if is(word, "if")));
+        if !branches {
+            let line = std::iter::once(header)
+                .chain(body.iter().map(|token| self.token(token)))
+                .chain(std::iter::once(end.to_string()))
+                .filter(|text| !text.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ");
+            return vec![line];
+        }
+
+        // Each line of the body starts at an indent of one level, and
+        // `ELSE` and `THEN` go back out to the level of their `IF`.
+        let mut lines = vec![header];
+        let mut line = String::new();
+        let mut indent = 1;
+        for token in body {
+            let text = self.token(token);
+            match token {
+                Token::Word(word) if is(word, "if") => {
+                    append(&mut line, &text);
+                    flush(&mut lines, &mut line, indent);
+                    indent += 1;
+                }
+                Token::Word(word) if is(word, "else") => {
+                    flush(&mut lines, &mut line, indent);
+                    line = text;
+                    flush(&mut lines, &mut line, (indent - 1).max(1));
+                }
+                Token::Word(word) if is(word, "then") => {
+                    flush(&mut lines, &mut line, indent);
+                    indent = (indent - 1).max(1);
+                    line = text;
+                }
+                _ => append(&mut line, &text),
+            }
+        }
+        append(&mut line, end);
+        flush(&mut lines, &mut line, indent);
+        lines
+    }
+}
+
+// End the current line of a definition's body, if anything is on it.
+fn flush(lines: &mut Vec<String>, line: &mut String, indent: usize) {
+    if !line.is_empty() {
+        lines.push(format!("{}{line}", "  ".repeat(indent)));
+        line.clear();
+    }
+}
+
+fn append(line: &mut String, text: &str) {
+    if !line.is_empty() && !text.is_empty() {
+        line.push(' ');
+    }
+    line.push_str(text);
+}
@@ -0,0 +1,49 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use crate::dictionary::Dictionary;
+use crate::intern::Interner;
+use crate::memory::Memory;
+use crate::{Forth, Options};
+
+/// A snapshot of an interpreter's definitions and data space, made by
+/// [`Forth::freeze`]. It is `Send + Sync`, so one loaded prelude can be
+/// shared by interpreters on many threads.
+#[derive(Clone)]
+pub struct Frozen {
+    // Names were folded with these rules when they were defined.
+    options: Options,
+    symbols: Arc<Interner>,
+    dictionary: Arc<Dictionary>,
+    memory: Arc<Memory>,
+    // So that `REQUIRE` does not load the prelude's files again.
+    included: Arc<HashSet<String>>,
+}
+
+impl Forth {
+    /// Snapshot the dictionary without copying the definitions. Later
+    /// changes to this interpreter do not show up in the snapshot.
+    pub fn freeze(&self) -> Frozen {
+        Frozen {
+            options: self.options,
+            symbols: Arc::clone(&self.symbols),
+            dictionary: Arc::clone(&self.dictionary),
+            memory: Arc::clone(&self.memory),
+            included: Arc::new(self.included.clone()),
+        }
+    }
+
+    /// An interpreter with an empty stack that starts from `frozen`, with
+    /// the same options as the interpreter it was frozen from. The
+    /// definitions are shared until the new interpreter first changes
+    /// them, when it takes its own copy.
+    pub fn from_frozen(frozen: &Frozen) -> Forth {
+        Forth {
+            symbols: Arc::clone(&frozen.symbols),
+            dictionary: Arc::clone(&frozen.dictionary),
+            memory: Arc::clone(&frozen.memory),
+            included: (*frozen.included).clone(),
+            ..Forth::with_options(frozen.options)
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::VecDeque;
+
+use crate::{Forth, Frame, Instruction, Op, Result, Value};
+
+/// The most recent ops executed while history is recorded, each with the
+/// interpreter state just before it ran.
+///
+/// Steps are numbered from when recording began, so an index stays valid
+/// until its step is dropped to keep within the limit. Only the stacks are
+/// recorded: data space, output and the dictionary are not rolled back.
+#[derive(Default)]
+pub struct History {
+    steps: VecDeque<Step>,
+    limit: usize,
+    // The index of the oldest step kept.
+    first: usize,
+    // The index of the step the data stack was last restored to, or the end
+    // of the history if it has not been.
+    position: usize,
+}
+
+/// One executed op.
+pub struct Step {
+    op: Op,
+    stack: Vec<Value>,
+    frames: Vec<Frame>,
+    locals: Vec<Value>,
+}
+
+impl Step {
+    pub fn instruction(&self) -> Instruction<'_> {
+        self.op.instruction()
+    }
+
+    /// The user word whose body was executing, or `None` at the top level.
+    pub fn word(&self) -> Option<&str> {
+        self.frames.last().map(|frame| &*frame.word.name)
+    }
+
+    /// The data stack just before the op ran.
+    pub fn stack(&self) -> &[Value] {
+        &self.stack
+    }
+}
+
+impl History {
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// The index of the oldest step kept.
+    pub fn first(&self) -> usize {
+        self.first
+    }
+
+    /// The index after the newest step.
+    pub fn end(&self) -> usize {
+        self.first + self.steps.len()
+    }
+
+    /// The index of the step the stack was last moved back to, or
+    /// [`end`](History::end) if it is at the newest state.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Step> {
+        self.steps.get(index.checked_sub(self.first)?)
+    }
+
+    /// The kept steps with their indices, oldest first.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &Step)> {
+        (self.first..).zip(&self.steps)
+    }
+
+    // Forget the steps from the position on, as a new run replaces them.
+    fn truncate(&mut self) {
+        self.steps.truncate(self.position - self.first);
+    }
+
+    fn push(&mut self, step: Step) {
+        self.truncate();
+        if self.steps.len() == self.limit {
+            self.steps.pop_front();
+            self.first += 1;
+        }
+        self.steps.push_back(step);
+        self.position = self.end();
+    }
+}
+
+impl Forth {
+    /// Record the state before each executed op, keeping the newest `limit`
+    /// steps. A limit of zero stops recording and drops the history.
+    ///
+    /// While recording, definitions run through the interpreter whichever
+    /// backend is selected.
+    pub fn record_history(&mut self, limit: usize) {
+        if limit == 0 {
+            self.history = None;
+            return;
+        }
+        let history = self.history.get_or_insert_with(History::default);
+        while history.steps.len() > limit {
+            history.steps.pop_front();
+            history.first += 1;
+        }
+        history.position = history.position.max(history.first);
+        history.limit = limit;
+    }
+
+    /// The recorded history, if it is being recorded.
+    pub fn history(&self) -> Option<&History> {
+        self.history.as_ref()
+    }
+
+    /// Move the data stack back to before the previous step, returning that
+    /// step's index, or `None` if no earlier step is kept.
+    pub fn step_back(&mut self) -> Option<usize> {
+        let position = self.history.as_ref()?.position.checked_sub(1)?;
+        self.rewind(position).then_some(position)
+    }
+
+    /// Move the data stack to how it was just before step `index`, forward
+    /// or back. The steps after it are kept until something else runs, or
+    /// they are replayed.
+    pub fn rewind(&mut self, index: usize) -> bool {
+        let Some(history) = self.history.as_mut() else {
+            return false;
+        };
+        let Some(step) = history.get(index) else {
+            return false;
+        };
+        self.data.clone_from(&step.stack);
+        history.position = index;
+        true
+    }
+
+    /// Run again from the step the stack was moved back to, with the
+    /// return stack and locals it had, until the top-level word it was
+    /// part of finishes. The steps it replaces are recorded afresh.
+    pub fn replay(&mut self) -> Result {
+        let Some(history) = self.history.as_mut() else {
+            return Ok(());
+        };
+        let Some(step) = history.get(history.position) else {
+            return Ok(());
+        };
+        let Step {
+            op,
+            stack,
+            frames,
+            locals,
+            ..
+        } = step;
+        let op = op.clone();
+        self.data.clone_from(stack);
+        self.frames.clone_from(frames);
+        self.locals.clone_from(locals);
+        history.truncate();
+
+        let result = self.execute_until(&op, 0);
+        if result.is_err() {
+            self.frames.clear();
+            self.locals.clear();
+        }
+        result
+    }
+
+    pub(super) fn record(&mut self, op: &Op) {
+        if let Some(history) = self.history.as_mut() {
+            history.push(Step {
+                op: op.clone(),
+                stack: self.data.clone(),
+                frames: self.frames.clone(),
+                locals: self.locals.clone(),
+            });
+        }
+    }
+}
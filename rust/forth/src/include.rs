@@ -0,0 +1,104 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use crate::token::Tokenizer;
+use crate::{Error, Forth, Op, Result};
+
+/// Finds the source text of the files named by `INCLUDE` and `REQUIRE`.
+pub trait Loader {
+    /// The contents of `name`, or `None` if it cannot be read.
+    fn load(&mut self, name: &str) -> Option<String>;
+}
+
+/// Files kept in memory, keyed by name. The default loader is an empty map.
+impl Loader for HashMap<String, String> {
+    fn load(&mut self, name: &str) -> Option<String> {
+        self.get(name).cloned()
+    }
+}
+
+/// Reads files from disk, relative to a root directory. Names that are
+/// absolute or go up with `..` are not loaded, so files outside the root
+/// can only be reached through links inside it.
+pub struct FileLoader {
+    root: PathBuf,
+}
+
+impl FileLoader {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        FileLoader { root: root.into() }
+    }
+}
+
+impl Loader for FileLoader {
+    fn load(&mut self, name: &str) -> Option<String> {
+        let path = Path::new(name);
+        let inside = path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+        if !inside {
+            return None;
+        }
+        fs::read_to_string(self.root.join(path)).ok()
+    }
+}
+
+impl Forth {
+    pub fn set_loader(&mut self, loader: impl Loader + 'static) {
+        self.loader = Box::new(loader);
+    }
+
+    // Interpret the file `name`. Errors inside it are wrapped with the file
+    // and line they happened on.
+    pub(super) fn include(
+        &mut self,
+        name: &str,
+        sink: &mut dyn FnMut(&mut Self, Op) -> Result,
+    ) -> Result {
+        // A file that includes itself, directly or not, would never finish
+        if self.including.iter().any(|file| file == name) {
+            return Err(Error::InvalidWord);
+        }
+        let source = self.loader.load(name).ok_or(Error::UnknownFile)?;
+
+        self.including.push(name.to_string());
+        let mut tokens = Tokenizer::new(&source);
+        let result = self.interpret_tokens(&mut tokens, sink);
+        self.including.pop();
+
+        // Only a file that loaded completely is skipped by `REQUIRE`
+        if result.is_ok() {
+            self.included.insert(name.to_string());
+        }
+        result.map_err(|error| Error::Included {
+            file: name.to_string(),
+            line: tokens.line(),
+            error: Box::new(error),
+        })
+    }
+
+    // Like `include`, but does nothing if `name` was loaded before or is
+    // being loaded.
+    pub(super) fn require(
+        &mut self,
+        name: &str,
+        sink: &mut dyn FnMut(&mut Self, Op) -> Result,
+    ) -> Result {
+        if self.included.contains(name) || self.including.iter().any(|file| file == name) {
+            return Ok(());
+        }
+        self.include(name, sink)
+    }
+}
+
+impl Error {
+    /// The error that caused this one, looking through the files it was
+    /// raised in.
+    pub fn root(&self) -> &Error {
+        match self {
+            Error::Included { error, .. } => error.root(),
+            error => error,
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// A word name, interned so the dictionary can be keyed without strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) struct Symbol(u32);
+
+#[derive(Clone, Default)]
+pub(crate) struct Interner {
+    symbols: HashMap<Arc<str>, Symbol>,
+    names: Vec<Arc<str>>,
+}
+
+impl Interner {
+    // `name` must already be folded with `CaseFolding::fold`.
+    pub(crate) fn intern(&mut self, name: &str) -> Symbol {
+        if let Some(&symbol) = self.symbols.get(name) {
+            return symbol;
+        }
+        let symbol = Symbol(self.names.len() as u32);
+        let name: Arc<str> = name.into();
+        self.names.push(Arc::clone(&name));
+        self.symbols.insert(name, symbol);
+        symbol
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<Symbol> {
+        self.symbols.get(name).copied()
+    }
+
+    pub(crate) fn name(&self, symbol: Symbol) -> &Arc<str> {
+        &self.names[symbol.0 as usize]
+    }
+}
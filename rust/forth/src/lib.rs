@@ -1,9 +1,52 @@
-use std::collections::HashMap;
-use std::rc::Rc;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::sync::Arc;
+
+mod closure;
+mod debug;
+mod dictionary;
+mod double;
+mod effect;
+pub mod ffi;
+mod format;
+mod frozen;
+mod history;
+mod include;
+mod intern;
+mod lint;
+mod memory;
+mod optimize;
+mod options;
+mod pictured;
+mod syntax;
+mod task;
+mod token;
+
+pub use debug::{Event, Resume, Tracer};
+pub use double::Double;
+pub use effect::StackEffect;
+pub use format::format;
+pub use frozen::Frozen;
+pub use history::{History, Step};
+pub use include::{FileLoader, Loader};
+pub use lint::{Lint, LintKind, lint};
+pub use options::{Backend, CaseFolding, Options};
+pub use task::{Status, TaskId};
+
+use closure::Code;
+use dictionary::{Dictionary, FORTH, Mark};
+use intern::Interner;
+use memory::Memory;
+use syntax::Parses;
+use task::Task;
+use token::Tokenizer;
 
 pub type Value = i32;
 pub type Result = std::result::Result<(), Error>;
 
+// How many user words may be active at once.
+const MAX_CALL_DEPTH: usize = 1 << 16;
+
 #[derive(Clone)]
 enum Op {
     Add,
@@ -14,13 +57,180 @@ enum Op {
     Drop,
     Swap,
     Over,
+    Equal,
+    Less,
+    Greater,
+    Words,
+    Pause,
+    // Search order words; `Context` replaces the first wordlist searched,
+    // and has the name of its vocabulary.
+    Context(usize, Arc<str>),
+    Also,
+    Only,
+    Previous,
+    Definitions,
+    Order,
+    // Roll the dictionary back to the state saved when the marker was made.
+    Marker(Arc<Mark>),
+    Type,
+    Count,
+    Compare,
+    Search,
+    SlashString,
+    CMove,
+    CFetch,
+    CStore,
+    // The address in data space of a string compiled by `S"`, and its text.
+    String(Value, Arc<str>),
+    DAdd,
+    DSub,
+    DDot,
+    MStar,
+    UMStar,
+    UMSlashMod,
+    SToD,
+    DToS,
+    DEqual,
+    DLess,
+    DGreater,
+    DZeroEqual,
+    DZeroLess,
+    LessNumberSign,
+    NumberSign,
+    NumberSignS,
+    Hold,
+    Sign,
+    NumberSignGreater,
+    Dot,
+    DotR,
+    UDot,
+    UDotR,
     Push(Value),
-    Word(Rc<[Op]>),
+    PushDouble(Double),
+    Word(Arc<Word>),
+    // A call to the word currently executing.
+    Recurse,
+    // Jumps to an index in the current word's body.
+    Branch(usize),
+    BranchIfZero(usize),
+    // Move the arguments of a word from the data stack into its locals.
+    Locals(Arc<Locals>),
+    // Read and write a local of the current word by slot.
+    Local(usize, Arc<str>),
+    ToLocal(usize, Arc<str>),
+    // Fail with `StackUnderflow` unless this many cells are on the stack;
+    // left behind by the optimizer when it removes ops that could underflow.
+    Need(usize),
+    // As `Need`, but first dropping a cell if there is one, as a failing
+    // `SWAP` or `OVER` does.
+    NeedOrDrop(usize),
+}
+
+struct Word {
+    name: Arc<str>,
+    body: Box<[Op]>,
+    effect: Option<StackEffect>,
+    // The body compiled to closures, when that backend is selected.
+    code: Option<Box<[Code]>>,
+}
+
+// The locals declared by `{: args | uninitialized -- comment :}`.
+struct Locals {
+    names: Box<[Box<str>]>,
+    args: usize,
+}
+
+// An activation of a user word on the return stack.
+#[derive(Clone)]
+struct Frame {
+    word: Arc<Word>,
+    ip: usize,
+    // Where this word's locals start on the locals stack.
+    locals: usize,
+}
+
+/// A read-only view of one compiled instruction in a user definition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction<'a> {
+    Builtin(&'static str),
+    Literal(Value),
+    DoubleLiteral(Double),
+    Call(&'a str),
+    Recurse,
+    /// Jump to the instruction at this index.
+    Branch(usize),
+    /// Pop a flag and jump to the instruction at this index if it is zero.
+    BranchIfZero(usize),
+    /// Declare locals; the first `args` are taken from the data stack.
+    Locals {
+        names: &'a [Box<str>],
+        args: usize,
+    },
+    Local(&'a str),
+    ToLocal(&'a str),
+    /// Make the wordlist of the vocabulary with this name the first one
+    /// searched.
+    Context(&'a str),
+    Marker,
+    /// A string literal compiled by `S"`.
+    String(&'a str),
+    /// A stack depth check left where optimized-away words could have
+    /// underflowed.
+    Need(usize),
+    /// A stack depth check that also drops a cell when it fails, left
+    /// where optimized-away `SWAP` or `OVER` could have underflowed.
+    NeedOrDrop(usize),
+}
+
+/// A read-only view of a user definition, as returned by [`Forth::definition`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Definition<'a> {
+    pub name: &'a str,
+    pub body: Vec<Instruction<'a>>,
+}
+
+/// Top-level code compiled once by [`Forth::compile`], to be run any number
+/// of times by [`Forth::run`].
+#[derive(Clone)]
+pub struct Program {
+    ops: Arc<[Op]>,
 }
 
 pub struct Forth {
+    options: Options,
     data: Vec<Value>,
-    definitions: HashMap<String, Rc<[Op]>>,
+    // Shared with frozen copies, and copied on the first change.
+    symbols: Arc<Interner>,
+    dictionary: Arc<Dictionary>,
+    memory: Arc<Memory>,
+    // Reused to case-fold each token before it is looked up.
+    folded: String,
+    // Set while `compile` collects top-level code instead of running it.
+    compiling: bool,
+    output: String,
+    // The pictured number being built by `<# ... #>`, last character first.
+    hold: Vec<u8>,
+    frames: Vec<Frame>,
+    locals: Vec<Value>,
+    tracer: Option<Box<dyn Tracer>>,
+    breakpoints: HashSet<String>,
+    stepping: bool,
+    history: Option<History>,
+    // The lowest data stack depth any op has reached into while `call`
+    // runs a word.
+    low_water: Option<usize>,
+    optimize: bool,
+    loader: Box<dyn Loader>,
+    // Every file loaded without error so far, which `REQUIRE` skips.
+    included: HashSet<String>,
+    // The files being interpreted, outermost first.
+    including: Vec<String>,
+    // Tasks waiting for their turn, next first.
+    tasks: VecDeque<Task>,
+    next_task: u32,
+    in_task: bool,
+    // Set by `PAUSE` to stop the running task.
+    pausing: bool,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -29,13 +239,54 @@ pub enum Error {
     StackUnderflow,
     UnknownWord,
     InvalidWord,
+    Aborted,
+    StackEffectMismatch,
+    ReturnStackOverflow,
+    UnknownFile,
+    InvalidAddress,
+    DataSpaceOverflow,
+    Overflow,
+    /// A field width given to `.R` or `U.R` is too large.
+    InvalidWidth,
+    /// Raised while interpreting an included file, at this line of it.
+    Included {
+        file: String,
+        line: usize,
+        error: Box<Error>,
+    },
 }
 
 impl Forth {
     pub fn new() -> Forth {
+        Forth::with_options(Options::default())
+    }
+
+    pub fn with_options(options: Options) -> Forth {
         Forth {
+            options,
             data: Vec::new(),
-            definitions: HashMap::new(),
+            symbols: Arc::default(),
+            dictionary: Arc::new(Dictionary::new()),
+            memory: Arc::default(),
+            folded: String::new(),
+            compiling: false,
+            output: String::new(),
+            hold: Vec::new(),
+            frames: Vec::new(),
+            locals: Vec::new(),
+            tracer: None,
+            breakpoints: HashSet::new(),
+            stepping: false,
+            history: None,
+            low_water: None,
+            optimize: false,
+            loader: Box::new(HashMap::new()),
+            included: HashSet::new(),
+            including: Vec::new(),
+            tasks: VecDeque::new(),
+            next_task: 0,
+            in_task: false,
+            pausing: false,
         }
     }
 
@@ -43,61 +294,364 @@ impl Forth {
         &self.data
     }
 
+    /// Text printed so far by words such as `WORDS` and `SEE`.
+    pub fn output(&self) -> &str {
+        &self.output
+    }
+
+    /// Return the printed text, leaving the output buffer empty.
+    pub fn take_output(&mut self) -> String {
+        std::mem::take(&mut self.output)
+    }
+
+    /// Names of the user definitions in the first wordlist of the search
+    /// order, most recently defined first.
+    pub fn words(&self) -> impl Iterator<Item = &str> {
+        self.dictionary
+            .words()
+            .map(|symbol| &**self.symbols.name(symbol))
+    }
+
+    /// The current definition of the user word `name`, if there is one.
+    pub fn definition(&self, name: &str) -> Option<Definition<'_>> {
+        let word = self.find(name)?;
+        Some(Definition {
+            name: &word.name,
+            body: word.body.iter().map(Op::instruction).collect(),
+        })
+    }
+
+    /// The declared or inferred stack effect of `name`, if it is statically
+    /// known.
+    ///
+    /// A declaration is checked against the effect inferred from the body.
+    /// Where none can be inferred, as for branches that leave different
+    /// depths, `RECURSE` or words of unknown effect, the declaration is
+    /// trusted as written.
+    pub fn stack_effect(&self, name: &str) -> Option<StackEffect> {
+        match self.find(name) {
+            Some(word) => word.effect,
+            None => Op::builtin(&self.fold_name(name))?.effect(),
+        }
+    }
+
     pub fn eval(&mut self, input: &str) -> Result {
-        let mut iter = input.split_whitespace();
+        self.interpret(input, &mut |forth, op| forth.execute_op(&op))
+    }
 
-        while let Some(token) = iter.next() {
-            if token == ":" {
-                self.parse_definition(&mut iter)?;
-            } else {
+    /// Compile top-level code without running it. Definitions and other
+    /// parsing words in `input` take effect immediately.
+    ///
+    /// `BUFFER:` takes its size from the stack as it is read, so it fails
+    /// with `InvalidWord` here.
+    pub fn compile(&mut self, input: &str) -> std::result::Result<Program, Error> {
+        let mut ops = Vec::new();
+        self.compiling = true;
+        let result = self.interpret(input, &mut |_, op| {
+            ops.push(op);
+            Ok(())
+        });
+        self.compiling = false;
+        result?;
+        if self.optimize {
+            ops = optimize::optimize(ops);
+        }
+        Ok(Program { ops: ops.into() })
+    }
+
+    pub fn run(&mut self, program: &Program) -> Result {
+        program.ops.iter().try_for_each(|op| self.execute_op(op))
+    }
+
+    /// Run the word `name` on a stack holding only `args`, and return the
+    /// `N` cells it leaves, deepest first. The main stack is not touched.
+    ///
+    /// Fails with `StackEffectMismatch` if the word's stack effect does not
+    /// take `args.len()` cells and leave `N`, or if when run it leaves a
+    /// different number of cells. A word without a known stack effect must
+    /// also use all of `args`, and is run through the interpreter to check
+    /// that whichever backend is selected.
+    pub fn call<const N: usize>(
+        &mut self,
+        name: &str,
+        args: &[Value],
+    ) -> std::result::Result<[Value; N], Error> {
+        let op = match self.find(name) {
+            Some(word) => Op::Word(Arc::clone(word)),
+            None => Op::builtin(&self.fold_name(name)).ok_or(Error::UnknownWord)?,
+        };
+        let effect = op.effect();
+        if effect.is_some_and(|effect| effect != StackEffect::new(args.len(), N)) {
+            return Err(Error::StackEffectMismatch);
+        }
+
+        let stack = std::mem::replace(&mut self.data, args.to_vec());
+        self.low_water = effect.is_none().then_some(args.len());
+        let result = self.execute_op(&op);
+        let low_water = self.low_water.take();
+        let results = std::mem::replace(&mut self.data, stack);
+        result?;
+        if low_water.is_some_and(|low_water| low_water > 0) {
+            return Err(Error::StackEffectMismatch);
+        }
+        results.try_into().map_err(|_| Error::StackEffectMismatch)
+    }
+
+    // The outer interpreter: handle parsing words, and pass every other
+    // compiled op to `sink`.
+    fn interpret(&mut self, input: &str, sink: &mut dyn FnMut(&mut Self, Op) -> Result) -> Result {
+        self.interpret_tokens(&mut Tokenizer::new(input), sink)
+    }
+
+    fn interpret_tokens(
+        &mut self,
+        tokens: &mut Tokenizer,
+        sink: &mut dyn FnMut(&mut Self, Op) -> Result,
+    ) -> Result {
+        while let Some(token) = tokens.next() {
+            let Some((word, parses, compile_only)) = syntax::parsing_word(token, self.options.case)
+            else {
                 let op = self.token_to_op(token)?;
-                self.execute_op(&op)?;
+                sink(self, op)?;
+                continue;
+            };
+            if compile_only {
+                return Err(Error::InvalidWord);
+            }
+            let text = match parses {
+                Parses::Until(end) => tokens.parse_until(end),
+                Parses::Word | Parses::NewWord | Parses::File => {
+                    tokens.next().ok_or(Error::InvalidWord)?
+                }
+                Parses::Nothing | Parses::Definition | Parses::Words(_) => "",
+            };
+            match word {
+                ":" => self.parse_definition(tokens)?,
+                "(" => {}
+                "see" => self.see(text)?,
+                "vocabulary" => {
+                    self.check_name(text)?;
+                    let folded = self.fold_name(text);
+                    let wordlist = Arc::make_mut(&mut self.dictionary).add_wordlist(folded.into());
+                    self.create(
+                        text,
+                        vec![Op::Context(wordlist, text.into())],
+                        StackEffect::new(0, 0).into(),
+                    )?;
+                }
+                "marker" => {
+                    let mark = Arc::new(self.dictionary.mark());
+                    self.create(text, vec![Op::Marker(mark)], StackEffect::new(0, 0).into())?;
+                }
+                "s\"" => {
+                    let op = self.string(text, !self.compiling)?;
+                    sink(self, op)?;
+                }
+                "buffer:" => {
+                    if self.compiling {
+                        return Err(Error::InvalidWord);
+                    }
+                    let len = self.data.last().ok_or(Error::StackUnderflow)?;
+                    let addr = Arc::make_mut(&mut self.memory).allot(*len)?;
+                    self.data.pop();
+                    self.create(text, vec![Op::Push(addr)], StackEffect::new(0, 1).into())?;
+                }
+                "include" => self.include(text, sink)?,
+                "require" => self.require(text, sink)?,
+                "forget" => self.forget(text)?,
+                _ => {
+                    let op = self.token_to_op(token)?;
+                    sink(self, op)?;
+                }
             }
         }
 
         Ok(())
     }
 
-    fn parse_definition<'a>(&mut self, iter: &mut impl Iterator<Item = &'a str>) -> Result {
-        let name = iter.next().ok_or(Error::InvalidWord)?;
-        if name.parse::<Value>().is_ok() {
-            return Err(Error::InvalidWord);
-        }
+    fn parse_definition(&mut self, tokens: &mut Tokenizer) -> Result {
+        let name = tokens.next().ok_or(Error::InvalidWord)?;
+        self.check_name(name)?;
+
+        let mut folded_name = String::new();
+        self.options.case.fold(name, &mut folded_name);
 
         let mut definition_ops = Vec::new();
+        let mut declared: Option<StackEffect> = None;
+        // Indexes of the branches still waiting for their `ELSE` or `THEN`.
+        let mut unresolved = Vec::new();
+        let mut recursive = false;
+        let mut locals: Option<Arc<Locals>> = None;
         loop {
-            match iter.next() {
+            match tokens.next() {
                 Some(";") => {
-                    self.definitions
-                        .insert(name.to_lowercase(), definition_ops.into());
-                    return Ok(());
+                    if !unresolved.is_empty() {
+                        return Err(Error::InvalidWord);
+                    }
+                    if self.optimize {
+                        definition_ops = optimize::optimize(definition_ops);
+                    }
+                    let effect = match (declared, StackEffect::infer(&definition_ops)) {
+                        (Some(declared), Some(inferred)) if !declared.admits(inferred) => {
+                            return Err(Error::StackEffectMismatch);
+                        }
+                        (declared, inferred) => declared.or(inferred),
+                    };
+                    return self.create(name, definition_ops, effect);
+                }
+                Some("(") => {
+                    let comment = tokens.parse_until(')');
+                    if definition_ops.is_empty() && declared.is_none() {
+                        declared = StackEffect::parse(comment);
+                    }
+                }
+                Some(token) if self.options.case.matches(token, "if") => {
+                    unresolved.push(definition_ops.len());
+                    definition_ops.push(Op::BranchIfZero(usize::MAX));
+                }
+                Some(token) if self.options.case.matches(token, "else") => {
+                    let branch = unresolved.pop().ok_or(Error::InvalidWord)?;
+                    unresolved.push(definition_ops.len());
+                    definition_ops.push(Op::Branch(usize::MAX));
+                    let target = definition_ops.len();
+                    definition_ops[branch].resolve(target);
+                }
+                Some(token) if self.options.case.matches(token, "then") => {
+                    let branch = unresolved.pop().ok_or(Error::InvalidWord)?;
+                    let target = definition_ops.len();
+                    definition_ops[branch].resolve(target);
+                }
+                Some(token) if self.options.case.matches(token, "s\"") => {
+                    definition_ops.push(self.string(tokens.parse_until('"'), false)?);
+                }
+                Some(token) if self.options.case.matches(token, "recurse") => {
+                    definition_ops.push(Op::Recurse);
+                }
+                Some(token) if self.options.case.matches(token, "recursive") => {
+                    recursive = true;
+                }
+                Some("{:") => {
+                    if !definition_ops.is_empty() || locals.is_some() {
+                        return Err(Error::InvalidWord);
+                    }
+                    let declared = Arc::new(parse_locals(tokens, self.options.case)?);
+                    definition_ops.push(Op::Locals(Arc::clone(&declared)));
+                    locals = Some(declared);
+                }
+                Some(token) if self.options.case.matches(token, "to") => {
+                    let name = tokens.next().ok_or(Error::InvalidWord)?;
+                    self.options.case.fold(name, &mut self.folded);
+                    let (slot, name) = locals
+                        .as_ref()
+                        .and_then(|locals| locals.find(&self.folded))
+                        .ok_or(Error::InvalidWord)?;
+                    definition_ops.push(Op::ToLocal(slot, name));
                 }
                 Some(token) => {
-                    definition_ops.push(self.token_to_op(token)?);
+                    self.options.case.fold(token, &mut self.folded);
+                    if let Some((slot, name)) =
+                        locals.as_ref().and_then(|locals| locals.find(&self.folded))
+                    {
+                        definition_ops.push(Op::Local(slot, name));
+                    } else if recursive && self.folded == folded_name {
+                        definition_ops.push(Op::Recurse);
+                    } else {
+                        definition_ops.push(self.token_to_op(token)?);
+                    }
                 }
                 None => return Err(Error::InvalidWord),
             }
         }
     }
 
-    fn token_to_op(&self, token: &str) -> std::result::Result<Op, Error> {
-        let lower_token = token.to_lowercase();
-        if let Some(def) = self.definitions.get(&lower_token) {
+    // Add a user word to the current wordlist.
+    fn create(&mut self, name: &str, body: Vec<Op>, effect: Option<StackEffect>) -> Result {
+        self.check_name(name)?;
+        self.options.case.fold(name, &mut self.folded);
+        let symbol = Arc::make_mut(&mut self.symbols).intern(&self.folded);
+        let word = Word {
+            name: Arc::clone(self.symbols.name(symbol)),
+            code: self.compile_code(&body),
+            body: body.into(),
+            effect,
+        };
+        Arc::make_mut(&mut self.dictionary).define(symbol, Arc::new(word));
+        Ok(())
+    }
+
+    // Numbers can never be redefined, and built-ins only when the options
+    // allow it.
+    fn check_name(&mut self, name: &str) -> Result {
+        if is_number(name) {
+            return Err(Error::InvalidWord);
+        }
+        if self.options.reserve_builtins {
+            self.options.case.fold(name, &mut self.folded);
+            if is_reserved(&self.folded) {
+                return Err(Error::InvalidWord);
+            }
+        }
+        Ok(())
+    }
+
+    // Compile a string literal, stored as a counted string in data space.
+    // One that is run straight away only needs a transient area.
+    fn string(&mut self, text: &str, transient: bool) -> std::result::Result<Op, Error> {
+        let addr = Arc::make_mut(&mut self.memory).store_counted(text, transient)?;
+        Ok(Op::String(addr, text.into()))
+    }
+
+    fn forget(&mut self, name: &str) -> Result {
+        self.options.case.fold(name, &mut self.folded);
+        match self.symbols.get(&self.folded) {
+            Some(symbol) if Arc::make_mut(&mut self.dictionary).forget(symbol) => Ok(()),
+            _ if Op::builtin(&self.folded).is_some() => Err(Error::InvalidWord),
+            _ => Err(Error::UnknownWord),
+        }
+    }
+
+    fn see(&mut self, name: &str) -> Result {
+        use std::fmt::Write;
+
+        if let Some(definition) = self.definition(name) {
+            let source = definition.to_string();
+            writeln!(self.output, "{source}").unwrap();
+            return Ok(());
+        }
+        match Op::builtin(&self.fold_name(name)) {
+            Some(op) => writeln!(self.output, "{} is built-in", op.name()).unwrap(),
+            None => return Err(Error::UnknownWord),
+        }
+        Ok(())
+    }
+
+    // Fold a name from the public API, where an allocation does not matter.
+    fn fold_name(&self, name: &str) -> String {
+        let mut folded = String::new();
+        self.options.case.fold(name, &mut folded);
+        folded
+    }
+
+    fn find(&self, name: &str) -> Option<&Arc<Word>> {
+        let symbol = self.symbols.get(&self.fold_name(name))?;
+        self.dictionary.find(symbol)
+    }
+
+    fn token_to_op(&mut self, token: &str) -> std::result::Result<Op, Error> {
+        self.options.case.fold(token, &mut self.folded);
+        if let Some(symbol) = self.symbols.get(&self.folded)
+            && let Some(def) = self.dictionary.find(symbol)
+        {
             return Ok(Op::Word(def.clone()));
         }
 
-        match lower_token.as_str() {
-            "+" => Ok(Op::Add),
-            "-" => Ok(Op::Sub),
-            "*" => Ok(Op::Mul),
-            "/" => Ok(Op::Div),
-            "dup" => Ok(Op::Dup),
-            "drop" => Ok(Op::Drop),
-            "swap" => Ok(Op::Swap),
-            "over" => Ok(Op::Over),
-            _ => {
+        match Op::builtin(&self.folded) {
+            Some(op) => Ok(op),
+            None => {
                 if let Ok(val) = token.parse::<Value>() {
                     Ok(Op::Push(val))
+                } else if let Some(double) = double::parse(token) {
+                    Ok(Op::PushDouble(double))
                 } else {
                     Err(Error::UnknownWord)
                 }
@@ -106,6 +660,107 @@ impl Forth {
     }
 
     fn execute_op(&mut self, op: &Op) -> Result {
+        let base = self.frames.len();
+        let locals = self.locals.len();
+        let result = self.execute_until(op, base);
+        if result.is_err() {
+            self.frames.truncate(base);
+            self.locals.truncate(locals);
+        }
+        result
+    }
+
+    // Execute `op` and, if it entered a user word, keep stepping through
+    // the return stack until control comes back down to `base`.
+    fn execute_until(&mut self, op: &Op, base: usize) -> Result {
+        self.step(op)?;
+        self.execute_frames(base)
+    }
+
+    // Keep stepping through the return stack until control comes back down
+    // to `base`, or the running task pauses.
+    fn execute_frames(&mut self, base: usize) -> Result {
+        if self.options.backend == Backend::Closures
+            && self.tracer.is_none()
+            && self.history.is_none()
+            && self.low_water.is_none()
+        {
+            return self.execute_compiled(base);
+        }
+        while self.frames.len() > base && !self.pausing {
+            let top = self.frames.len() - 1;
+            let frame = &mut self.frames[top];
+            let word = Arc::clone(&frame.word);
+            match word.body.get(frame.ip) {
+                Some(op) => {
+                    frame.ip += 1;
+                    self.step(op)?;
+                }
+                None => {
+                    let frame = self.frames.pop().expect("frames above base");
+                    self.locals.truncate(frame.locals);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn step(&mut self, op: &Op) -> Result {
+        if self.tracer.is_some() {
+            self.check_break(op)?;
+        }
+        self.record(op);
+        match op {
+            Op::Word(word) => self.enter(word),
+            Op::Recurse => self.recurse(),
+            _ => {
+                if let Some(low_water) = &mut self.low_water {
+                    let low = self.data.len().saturating_sub(op.reads());
+                    *low_water = (*low_water).min(low);
+                }
+                self.trace_before(op);
+                let result = self.execute_primitive(op);
+                self.trace_after(op, &result);
+                result
+            }
+        }
+    }
+
+    fn enter(&mut self, word: &Arc<Word>) -> Result {
+        if self.frames.len() >= MAX_CALL_DEPTH {
+            return Err(Error::ReturnStackOverflow);
+        }
+        self.frames.push(Frame {
+            word: Arc::clone(word),
+            ip: 0,
+            locals: self.locals.len(),
+        });
+        Ok(())
+    }
+
+    fn recurse(&mut self) -> Result {
+        let frame = self
+            .frames
+            .last_mut()
+            .expect("RECURSE is only compiled into words");
+        // A self call as the last thing a word does starts it again in the
+        // same frame, so recursive loops run in constant space.
+        if returns_from(&frame.word.body, frame.ip) {
+            frame.ip = 0;
+            return Ok(());
+        }
+        let word = Arc::clone(&frame.word);
+        self.enter(&word)
+    }
+
+    fn compile_code(&self, body: &[Op]) -> Option<Box<[Code]>> {
+        match self.options.backend {
+            Backend::Interpreter => None,
+            Backend::Closures => Some(closure::compile(body)),
+        }
+    }
+
+    fn execute_primitive(&mut self, op: &Op) -> Result {
         match op {
             Op::Add => self.calculate(Value::checked_add),
             Op::Sub => self.calculate(Value::checked_sub),
@@ -115,19 +770,146 @@ impl Forth {
             Op::Drop => self.drop(),
             Op::Swap => self.swap_over(false),
             Op::Over => self.swap_over(true),
+            Op::Equal => self.calculate(|a, b| Some(flag(a == b))),
+            Op::Less => self.calculate(|a, b| Some(flag(a < b))),
+            Op::Greater => self.calculate(|a, b| Some(flag(a > b))),
+            Op::Words => {
+                let line = self.words().collect::<Vec<_>>().join(" ");
+                self.output.push_str(&line);
+                self.output.push('\n');
+                Ok(())
+            }
             Op::Push(val) => {
                 self.data.push(*val);
                 Ok(())
             }
-            Op::Word(def) => {
-                for inner_op in def.iter() {
-                    self.execute_op(inner_op)?;
+            Op::Need(depth) => {
+                if self.data.len() < *depth {
+                    return Err(Error::StackUnderflow);
+                }
+                Ok(())
+            }
+            Op::NeedOrDrop(depth) => {
+                if self.data.len() < *depth {
+                    self.data.pop();
+                    return Err(Error::StackUnderflow);
+                }
+                Ok(())
+            }
+            Op::Branch(target) => {
+                self.jump(*target);
+                Ok(())
+            }
+            Op::BranchIfZero(target) => {
+                if self.data.pop().ok_or(Error::StackUnderflow)? == 0 {
+                    self.jump(*target);
+                }
+                Ok(())
+            }
+            Op::Pause => {
+                self.pause();
+                Ok(())
+            }
+            Op::Context(wordlist, _) => {
+                Arc::make_mut(&mut self.dictionary).set_context(*wordlist);
+                Ok(())
+            }
+            Op::Also => Arc::make_mut(&mut self.dictionary).also(),
+            Op::Only => {
+                Arc::make_mut(&mut self.dictionary).only();
+                Ok(())
+            }
+            Op::Previous => Arc::make_mut(&mut self.dictionary).previous(),
+            Op::Definitions => Arc::make_mut(&mut self.dictionary).definitions(),
+            Op::Order => {
+                let order = self.dictionary.order();
+                self.output.push_str(&order);
+                self.output.push('\n');
+                Ok(())
+            }
+            Op::Marker(mark) => {
+                Arc::make_mut(&mut self.dictionary).restore(mark);
+                Ok(())
+            }
+            Op::Type
+            | Op::Count
+            | Op::Compare
+            | Op::Search
+            | Op::SlashString
+            | Op::CMove
+            | Op::CFetch
+            | Op::CStore
+            | Op::String(..) => self.execute_memory(op),
+            Op::DAdd
+            | Op::DSub
+            | Op::DDot
+            | Op::MStar
+            | Op::UMStar
+            | Op::UMSlashMod
+            | Op::SToD
+            | Op::DToS
+            | Op::DEqual
+            | Op::DLess
+            | Op::DGreater
+            | Op::DZeroEqual
+            | Op::DZeroLess
+            | Op::PushDouble(_) => self.execute_double(op),
+            Op::LessNumberSign
+            | Op::NumberSign
+            | Op::NumberSignS
+            | Op::Hold
+            | Op::Sign
+            | Op::NumberSignGreater
+            | Op::Dot
+            | Op::DotR
+            | Op::UDot
+            | Op::UDotR => self.execute_pictured(op),
+            Op::Locals(locals) => {
+                if self.data.len() < locals.args {
+                    return Err(Error::StackUnderflow);
                 }
+                // A tail call back into the same word reuses the frame, so
+                // drop the locals of the previous pass first.
+                let base = self.locals_base();
+                self.locals.truncate(base);
+                let args = self.data.len() - locals.args;
+                self.locals.extend(self.data.drain(args..));
+                self.locals.resize(base + locals.names.len(), 0);
                 Ok(())
             }
+            Op::Local(slot, _) => {
+                let val = self.locals[self.locals_base() + slot];
+                self.data.push(val);
+                Ok(())
+            }
+            Op::ToLocal(slot, _) => {
+                let val = self.data.pop().ok_or(Error::StackUnderflow)?;
+                let base = self.locals_base();
+                self.locals[base + slot] = val;
+                Ok(())
+            }
+            Op::Word(_) | Op::Recurse => {
+                unreachable!("user words are entered by `step`")
+            }
         }
     }
 
+    fn locals_base(&self) -> usize {
+        let frame = self
+            .frames
+            .last()
+            .expect("locals are only compiled into words");
+        frame.locals
+    }
+
+    fn jump(&mut self, target: usize) {
+        let frame = self
+            .frames
+            .last_mut()
+            .expect("branches are only compiled into words");
+        frame.ip = target;
+    }
+
     fn calculate<F>(&mut self, operation: F) -> Result
     where
         F: Fn(Value, Value) -> Option<Value>,
@@ -167,19 +949,364 @@ impl Forth {
         }
     }
 
+    // The top `N` cells, deepest first, without removing them.
+    fn peek<const N: usize>(&self) -> std::result::Result<[Value; N], Error> {
+        let start = self
+            .data
+            .len()
+            .checked_sub(N)
+            .ok_or(Error::StackUnderflow)?;
+        Ok(self.data[start..].try_into().expect("exactly N cells"))
+    }
+
+    // Replace the top `taken` cells with `results`.
+    fn replace(&mut self, taken: usize, results: &[Value]) {
+        self.data.truncate(self.data.len() - taken);
+        self.data.extend_from_slice(results);
+    }
+
     fn swap_over(&mut self, over: bool) -> Result {
-        if let Some(last) = self.data.pop() {
-            if let Some(second_to_last) = self.data.pop() {
-                if over {
-                    // Put second_to_last back
-                    self.data.push(second_to_last);
-                }
-                // Put last and second_to_last back in swapped order
-                self.data.push(last);
+        if let Some(last) = self.data.pop()
+            && let Some(second_to_last) = self.data.pop()
+        {
+            if over {
+                // Put second_to_last back
                 self.data.push(second_to_last);
-                return Ok(());
             }
+            // Put last and second_to_last back in swapped order
+            self.data.push(last);
+            self.data.push(second_to_last);
+            return Ok(());
         }
         Err(Error::StackUnderflow)
     }
 }
+
+// Forth's canonical true and false flags.
+fn flag(condition: bool) -> Value {
+    if condition { -1 } else { 0 }
+}
+
+// Whether `token` would be read as a single or double-cell literal, and so
+// cannot name a word.
+fn is_number(token: &str) -> bool {
+    token.parse::<Value>().is_ok() || double::parse(token).is_some()
+}
+
+// Whether running a word's body from `ip` leaves it without doing anything
+// else.
+fn returns_from(body: &[Op], ip: usize) -> bool {
+    match body.get(ip) {
+        None => true,
+        Some(Op::Branch(target)) if *target > ip => returns_from(body, *target),
+        _ => false,
+    }
+}
+
+// Read a locals declaration up to its closing `:}`.
+fn parse_locals(tokens: &mut Tokenizer, case: CaseFolding) -> std::result::Result<Locals, Error> {
+    let mut names = Vec::new();
+    let mut args = None;
+    let mut comment = false;
+    loop {
+        match tokens.next().ok_or(Error::InvalidWord)? {
+            ":}" => break,
+            "|" if args.is_none() && !comment => args = Some(names.len()),
+            "--" => comment = true,
+            _ if comment => {}
+            name if is_number(name) => return Err(Error::InvalidWord),
+            name => {
+                let mut folded = String::new();
+                case.fold(name, &mut folded);
+                names.push(folded.into_boxed_str());
+            }
+        }
+    }
+    Ok(Locals {
+        args: args.unwrap_or(names.len()),
+        names: names.into(),
+    })
+}
+
+impl Locals {
+    // The slot of the local called `name`; later declarations shadow
+    // earlier ones.
+    fn find(&self, name: &str) -> Option<(usize, Arc<str>)> {
+        let slot = self.names.iter().rposition(|local| **local == *name)?;
+        Some((slot, self.names[slot].as_ref().into()))
+    }
+}
+
+// Whether the lowercase `name` is a built-in, compile-only or parsing word.
+fn is_reserved(name: &str) -> bool {
+    Op::builtin(name).is_some() || syntax::parsing_word(name, CaseFolding::Sensitive).is_some()
+}
+
+impl Op {
+    fn builtin(name: &str) -> Option<Op> {
+        match name {
+            "+" => Some(Op::Add),
+            "-" => Some(Op::Sub),
+            "*" => Some(Op::Mul),
+            "/" => Some(Op::Div),
+            "dup" => Some(Op::Dup),
+            "drop" => Some(Op::Drop),
+            "swap" => Some(Op::Swap),
+            "over" => Some(Op::Over),
+            "=" => Some(Op::Equal),
+            "<" => Some(Op::Less),
+            ">" => Some(Op::Greater),
+            "words" => Some(Op::Words),
+            "pause" => Some(Op::Pause),
+            "forth" => Some(Op::Context(FORTH, "forth".into())),
+            "also" => Some(Op::Also),
+            "only" => Some(Op::Only),
+            "previous" => Some(Op::Previous),
+            "definitions" => Some(Op::Definitions),
+            "order" => Some(Op::Order),
+            "type" => Some(Op::Type),
+            "count" => Some(Op::Count),
+            "compare" => Some(Op::Compare),
+            "search" => Some(Op::Search),
+            "/string" => Some(Op::SlashString),
+            "cmove" => Some(Op::CMove),
+            "c@" => Some(Op::CFetch),
+            "c!" => Some(Op::CStore),
+            "d+" => Some(Op::DAdd),
+            "d-" => Some(Op::DSub),
+            "d." => Some(Op::DDot),
+            "m*" => Some(Op::MStar),
+            "um*" => Some(Op::UMStar),
+            "um/mod" => Some(Op::UMSlashMod),
+            "s>d" => Some(Op::SToD),
+            "d>s" => Some(Op::DToS),
+            "d=" => Some(Op::DEqual),
+            "d<" => Some(Op::DLess),
+            "d>" => Some(Op::DGreater),
+            "d0=" => Some(Op::DZeroEqual),
+            "d0<" => Some(Op::DZeroLess),
+            "<#" => Some(Op::LessNumberSign),
+            "#" => Some(Op::NumberSign),
+            "#s" => Some(Op::NumberSignS),
+            "hold" => Some(Op::Hold),
+            "sign" => Some(Op::Sign),
+            "#>" => Some(Op::NumberSignGreater),
+            "." => Some(Op::Dot),
+            ".r" => Some(Op::DotR),
+            "u." => Some(Op::UDot),
+            "u.r" => Some(Op::UDotR),
+            _ => None,
+        }
+    }
+
+    fn name(&self) -> &'static str {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+            Op::Dup => "dup",
+            Op::Drop => "drop",
+            Op::Swap => "swap",
+            Op::Over => "over",
+            Op::Equal => "=",
+            Op::Less => "<",
+            Op::Greater => ">",
+            Op::Words => "words",
+            Op::Pause => "pause",
+            Op::Context(FORTH, _) => "forth",
+            Op::Also => "also",
+            Op::Only => "only",
+            Op::Previous => "previous",
+            Op::Definitions => "definitions",
+            Op::Order => "order",
+            Op::Type => "type",
+            Op::Count => "count",
+            Op::Compare => "compare",
+            Op::Search => "search",
+            Op::SlashString => "/string",
+            Op::CMove => "cmove",
+            Op::CFetch => "c@",
+            Op::CStore => "c!",
+            Op::DAdd => "d+",
+            Op::DSub => "d-",
+            Op::DDot => "d.",
+            Op::MStar => "m*",
+            Op::UMStar => "um*",
+            Op::UMSlashMod => "um/mod",
+            Op::SToD => "s>d",
+            Op::DToS => "d>s",
+            Op::DEqual => "d=",
+            Op::DLess => "d<",
+            Op::DGreater => "d>",
+            Op::DZeroEqual => "d0=",
+            Op::DZeroLess => "d0<",
+            Op::LessNumberSign => "<#",
+            Op::NumberSign => "#",
+            Op::NumberSignS => "#s",
+            Op::Hold => "hold",
+            Op::Sign => "sign",
+            Op::NumberSignGreater => "#>",
+            Op::Dot => ".",
+            Op::DotR => ".r",
+            Op::UDot => "u.",
+            Op::UDotR => "u.r",
+            Op::Context(..)
+            | Op::String(..)
+            | Op::Marker(_)
+            | Op::Push(_)
+            | Op::PushDouble(_)
+            | Op::Word(_)
+            | Op::Recurse
+            | Op::Branch(_)
+            | Op::BranchIfZero(_)
+            | Op::Locals(_)
+            | Op::Local(..)
+            | Op::ToLocal(..)
+            | Op::Need(_)
+            | Op::NeedOrDrop(_) => unreachable!("not a built-in"),
+        }
+    }
+
+    fn instruction(&self) -> Instruction<'_> {
+        match self {
+            Op::Push(val) => Instruction::Literal(*val),
+            Op::PushDouble(double) => Instruction::DoubleLiteral(*double),
+            Op::Word(word) => Instruction::Call(&word.name),
+            Op::Recurse => Instruction::Recurse,
+            Op::Branch(target) => Instruction::Branch(*target),
+            Op::BranchIfZero(target) => Instruction::BranchIfZero(*target),
+            Op::Locals(locals) => Instruction::Locals {
+                names: &locals.names,
+                args: locals.args,
+            },
+            Op::Context(wordlist, name) if *wordlist != FORTH => Instruction::Context(name),
+            Op::Marker(_) => Instruction::Marker,
+            Op::String(_, text) => Instruction::String(text),
+            Op::Local(_, name) => Instruction::Local(name),
+            Op::ToLocal(_, name) => Instruction::ToLocal(name),
+            Op::Need(depth) => Instruction::Need(*depth),
+            Op::NeedOrDrop(depth) => Instruction::NeedOrDrop(*depth),
+            op => Instruction::Builtin(op.name()),
+        }
+    }
+
+    // Point a forward branch at `target` once it is known.
+    fn resolve(&mut self, target: usize) {
+        match self {
+            Op::Branch(to) | Op::BranchIfZero(to) => *to = target,
+            _ => unreachable!("only branches are resolved"),
+        }
+    }
+
+    fn is_branch(&self) -> bool {
+        matches!(self, Op::Branch(_) | Op::BranchIfZero(_))
+    }
+}
+
+impl fmt::Display for Instruction<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Builtin(name) | Instruction::Call(name) => f.write_str(name),
+            Instruction::Literal(val) => write!(f, "{val}"),
+            Instruction::DoubleLiteral(double) => write!(f, "{double}."),
+            Instruction::Recurse => f.write_str("recurse"),
+            Instruction::Branch(target) => write!(f, "branch {target}"),
+            Instruction::BranchIfZero(target) => write!(f, "?branch {target}"),
+            Instruction::Locals { names, args } => {
+                f.write_str("{:")?;
+                for (slot, name) in names.iter().enumerate() {
+                    if slot == *args {
+                        f.write_str(" |")?;
+                    }
+                    write!(f, " {name}")?;
+                }
+                f.write_str(" :}")
+            }
+            Instruction::Local(name) => f.write_str(name),
+            Instruction::ToLocal(name) => write!(f, "to {name}"),
+            Instruction::Context(name) => f.write_str(name),
+            Instruction::Marker => f.write_str("( marker )"),
+            Instruction::String(text) => write!(f, "s\" {text}\""),
+            Instruction::Need(depth) => write!(f, "( need {depth} )"),
+            Instruction::NeedOrDrop(depth) => write!(f, "( need {depth} or drop )"),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::DivisionByZero => f.write_str("division by zero"),
+            Error::StackUnderflow => f.write_str("stack underflow"),
+            Error::UnknownWord => f.write_str("unknown word"),
+            Error::InvalidWord => f.write_str("invalid word"),
+            Error::Aborted => f.write_str("aborted"),
+            Error::StackEffectMismatch => f.write_str("stack effect mismatch"),
+            Error::ReturnStackOverflow => f.write_str("return stack overflow"),
+            Error::UnknownFile => f.write_str("unknown file"),
+            Error::InvalidAddress => f.write_str("invalid address"),
+            Error::DataSpaceOverflow => f.write_str("data space overflow"),
+            Error::Overflow => f.write_str("arithmetic overflow"),
+            Error::InvalidWidth => f.write_str("invalid field width"),
+            Error::Included { file, line, error } => write!(f, "{file}:{line}: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl fmt::Display for Definition<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut words = Vec::new();
+        if control_flow(&self.body, 0, self.body.len(), &mut words).is_none() {
+            words = self.body.iter().map(ToString::to_string).collect();
+        }
+        write!(f, ": {}", self.name)?;
+        for word in words {
+            write!(f, " {word}")?;
+        }
+        f.write_str(" ;")
+    }
+}
+
+// Write out `body[start..end]` with its branches turned back into the
+// `IF ... ELSE ... THEN` they were compiled from, or return `None` if they
+// do not nest that way.
+fn control_flow(
+    body: &[Instruction<'_>],
+    start: usize,
+    end: usize,
+    words: &mut Vec<String>,
+) -> Option<()> {
+    let mut ip = start;
+    while ip < end {
+        match body[ip] {
+            Instruction::BranchIfZero(target) if ip < target && target <= end => {
+                words.push("if".to_string());
+                let len = words.len();
+                // `ELSE` ends the true part with a jump over the false part
+                if let Instruction::Branch(after) = body[target - 1]
+                    && target - 1 > ip
+                    && (target..=end).contains(&after)
+                    && control_flow(body, ip + 1, target - 1, words).is_some()
+                {
+                    words.push("else".to_string());
+                    control_flow(body, target, after, words)?;
+                    ip = after;
+                } else {
+                    words.truncate(len);
+                    control_flow(body, ip + 1, target, words)?;
+                    ip = target;
+                }
+                words.push("then".to_string());
+            }
+            Instruction::Branch(_) | Instruction::BranchIfZero(_) => return None,
+            instruction => {
+                words.push(instruction.to_string());
+                ip += 1;
+            }
+        }
+    }
+    Some(())
+}
@@ -0,0 +1,230 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::effect::{self, Flow};
+use crate::syntax::{self, Definition, Item, Token, is};
+use crate::{Op, StackEffect, Value, double, is_reserved};
+
+/// A likely mistake found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lint {
+    /// The line, counting from 1, where the word is defined.
+    pub line: usize,
+    pub name: String,
+    pub kind: LintKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// A definition takes the name of a built-in or parsing word.
+    RedefinedBuiltin,
+    /// A colon definition is not used before it is redefined or the source
+    /// ends.
+    UnusedDefinition,
+    /// Every path through a definition takes more cells than it is given:
+    /// the inputs of its declared stack effect or, without one, the
+    /// arguments of its locals.
+    AlwaysUnderflows,
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Lint { line, name, kind } = self;
+        match kind {
+            LintKind::RedefinedBuiltin => write!(f, "{line}: `{name}` redefines a built-in word"),
+            LintKind::UnusedDefinition => write!(f, "{line}: `{name}` is never used"),
+            LintKind::AlwaysUnderflows => {
+                write!(f, "{line}: `{name}` always underflows the stack")
+            }
+        }
+    }
+}
+
+/// Check Forth source for likely mistakes, in order of line.
+///
+/// Files named by `INCLUDE` are not read, so words used only there are
+/// reported as unused. Names are matched ignoring case, as with the default
+/// [`Options`].
+///
+/// [`Options`]: crate::Options
+pub fn lint(source: &str) -> Vec<Lint> {
+    let mut linter = Linter::default();
+    for spanned in syntax::parse(source) {
+        let line = spanned.start;
+        let defined = spanned.item.defined_name();
+        if let Some(name) = defined
+            && is_reserved(&name.to_lowercase())
+        {
+            linter.report(line, name, LintKind::RedefinedBuiltin);
+        }
+        match (spanned.item, defined) {
+            (Item::Definition(definition), _) => linter.definition(line, &definition),
+            (Item::Token(Token::Parsing(word, _)), Some(name)) => {
+                let outputs = usize::from(is(word, "buffer:"));
+                linter.define(name, None, Some(StackEffect::new(0, outputs)));
+            }
+            (Item::Token(token), _) => linter.use_token(&token),
+        }
+    }
+
+    for (line, name, used) in std::mem::take(&mut linter.definitions) {
+        if !used {
+            linter.report(line, name, LintKind::UnusedDefinition);
+        }
+    }
+    linter.lints.sort_by_key(|lint| lint.line);
+    linter.lints
+}
+
+#[derive(Default)]
+struct Linter<'a> {
+    lints: Vec<Lint>,
+    // Each colon definition's line and name, and whether it has been used.
+    definitions: Vec<(usize, &'a str, bool)>,
+    // The latest definition of each word by its lowercase name.
+    visible: HashMap<String, Visible>,
+}
+
+struct Visible {
+    // The index of a colon definition in `definitions`.
+    definition: Option<usize>,
+    effect: Option<StackEffect>,
+}
+
+impl<'a> Linter<'a> {
+    fn report(&mut self, line: usize, name: &str, kind: LintKind) {
+        self.lints.push(Lint {
+            line,
+            name: name.to_string(),
+            kind,
+        });
+    }
+
+    fn define(&mut self, name: &str, definition: Option<usize>, effect: Option<StackEffect>) {
+        let visible = Visible { definition, effect };
+        self.visible.insert(name.to_lowercase(), visible);
+    }
+
+    fn use_word(&mut self, word: &str) {
+        let used = self.visible.get(&word.to_lowercase());
+        if let Some(index) = used.and_then(|visible| visible.definition) {
+            self.definitions[index].2 = true;
+        }
+    }
+
+    fn use_token(&mut self, token: &Token<'_>) {
+        match token {
+            Token::Word(word) => self.use_word(word),
+            Token::Parsing(word, name) if is(word, "see") || is(word, "forget") => {
+                self.use_word(name)
+            }
+            _ => {}
+        }
+    }
+
+    fn definition(&mut self, line: usize, definition: &Definition<'a>) {
+        let declared = match definition.body.first() {
+            Some(Token::Comment(comment)) => StackEffect::parse(comment),
+            _ => None,
+        };
+        let locals = definition.body.iter().find_map(|token| match token {
+            Token::Locals(words) => Some(&words[..]),
+            _ => None,
+        });
+        let args = locals.map(|words| {
+            let names = words.iter().take_while(|&&word| word != "--");
+            names.take_while(|&&word| word != "|").count()
+        });
+        let is_local = |word: &str| {
+            locals
+                .unwrap_or_default()
+                .iter()
+                .take_while(|&&local| local != "--")
+                .any(|local| local.eq_ignore_ascii_case(word))
+        };
+        let recursive = definition
+            .body
+            .iter()
+            .any(|token| matches!(token, Token::Word(word) if is(word, "recursive")));
+        let is_self = |word: &str| recursive && word.eq_ignore_ascii_case(definition.name);
+
+        // The body read as the compiler would lay it out, with each `IF` and
+        // `ELSE` jumping past its branch.
+        let mut flow = Vec::new();
+        let mut unresolved = Vec::new();
+        for token in &definition.body {
+            let effect = match token {
+                Token::Word(word) if is(word, "if") => {
+                    unresolved.push(flow.len());
+                    flow.push(Flow::JumpIfZero(usize::MAX));
+                    continue;
+                }
+                Token::Word(word) if is(word, "else") => {
+                    let branch = unresolved.pop();
+                    unresolved.push(flow.len());
+                    flow.push(Flow::Jump(usize::MAX));
+                    resolve(&mut flow, branch);
+                    continue;
+                }
+                Token::Word(word) if is(word, "then") => {
+                    let branch = unresolved.pop();
+                    resolve(&mut flow, branch);
+                    continue;
+                }
+                Token::Word(word) if is_local(word) => Some(StackEffect::new(0, 1)),
+                Token::Word(word) if is_self(word) || is(word, "recurse") => None,
+                Token::Word(word) if is(word, "recursive") => Some(StackEffect::new(0, 0)),
+                Token::Word(word) => {
+                    self.use_word(word);
+                    self.effect(word)
+                }
+                Token::Comment(_) => Some(StackEffect::new(0, 0)),
+                Token::String(..) => Some(StackEffect::new(0, 2)),
+                Token::Locals(_) => Some(StackEffect::new(args.unwrap_or_default(), 0)),
+                Token::Parsing(word, _) if is(word, "to") => Some(StackEffect::new(1, 0)),
+                Token::Parsing(..) => {
+                    self.use_token(token);
+                    None
+                }
+            };
+            flow.push(Flow::Run(effect));
+        }
+        let paths = effect::paths(&flow);
+
+        if let Some(inputs) = declared.map(|declared| declared.inputs).or(args) {
+            let inputs = inputs as isize;
+            if !paths.is_empty() && paths.iter().all(|path| path.low < -inputs) {
+                self.report(line, definition.name, LintKind::AlwaysUnderflows);
+            }
+        }
+        let inferred = StackEffect::of_paths(&paths);
+
+        self.definitions.push((line, definition.name, false));
+        let index = self.definitions.len() - 1;
+        self.define(definition.name, Some(index), declared.or(inferred));
+    }
+
+    fn effect(&self, word: &str) -> Option<StackEffect> {
+        let lower = word.to_lowercase();
+        if let Some(visible) = self.visible.get(&lower) {
+            visible.effect
+        } else if word.parse::<Value>().is_ok() {
+            Some(StackEffect::new(0, 1))
+        } else if double::parse(word).is_some() {
+            Some(StackEffect::new(0, 2))
+        } else {
+            Op::builtin(&lower)?.effect()
+        }
+    }
+}
+
+// Point the jump at `branch` past the end of `flow`. Unbalanced words are
+// left alone, as any text can be linted.
+fn resolve(flow: &mut [Flow], branch: Option<usize>) {
+    let target = flow.len();
+    if let Some(Flow::Jump(to) | Flow::JumpIfZero(to)) =
+        branch.and_then(|branch| flow.get_mut(branch))
+    {
+        *to = target;
+    }
+}
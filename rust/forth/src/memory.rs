@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use crate::{Error, Forth, Op, Result, Value, flag};
+
+// The most bytes of data space a program may allot.
+const MAX_DATA_SPACE: usize = 1 << 24;
+
+// The most characters a pictured number may have.
+pub(crate) const HOLD_SIZE: usize = 128;
+
+// How many strings from `Forth::push_str` and interpreted `S"` stay valid at
+// once; each new one reuses the space of the oldest.
+const TRANSIENT_STRINGS: usize = 8;
+
+/// Data space: a character buffer addressed by cell values, holding strings
+/// and buffers made by `S"` and `BUFFER:`.
+#[derive(Clone, Default)]
+pub(crate) struct Memory {
+    bytes: Vec<u8>,
+    // Where `#>` leaves the finished pictured number, allotted on first use.
+    hold: Option<Value>,
+    // The address and size of each transient string area, allotted on first
+    // use, and the one to use next.
+    transient: [Option<(Value, usize)>; TRANSIENT_STRINGS],
+    next_transient: usize,
+}
+
+impl Memory {
+    // Reserve `len` zeroed bytes and return the address of the first.
+    pub(crate) fn allot(&mut self, len: Value) -> std::result::Result<Value, Error> {
+        let len = usize::try_from(len).map_err(|_| Error::InvalidAddress)?;
+        let here = self.bytes.len();
+        if len > MAX_DATA_SPACE - here {
+            return Err(Error::DataSpaceOverflow);
+        }
+        self.bytes.resize(here + len, 0);
+        Ok(here as Value)
+    }
+
+    // Store `text` with a leading count byte, in a transient area if asked,
+    // and return the address of the text after the count.
+    pub(crate) fn store_counted(
+        &mut self,
+        text: &str,
+        transient: bool,
+    ) -> std::result::Result<Value, Error> {
+        let count = u8::try_from(text.len()).map_err(|_| Error::InvalidWord)?;
+        let bytes = [&[count], text.as_bytes()].concat();
+        let addr = if transient {
+            self.store_transient(&bytes)?
+        } else {
+            self.store(&bytes)?
+        };
+        Ok(addr + 1)
+    }
+
+    // Copy a pictured number into the hold area and return its address.
+    // The text is only valid until the next `#>`.
+    pub(crate) fn store_hold(&mut self, text: &[u8]) -> std::result::Result<Value, Error> {
+        let addr = match self.hold {
+            Some(addr) => addr,
+            None => {
+                let addr = self.allot(HOLD_SIZE as Value)?;
+                *self.hold.insert(addr)
+            }
+        };
+        self.get_mut(addr, text.len() as Value)?
+            .copy_from_slice(text);
+        Ok(addr)
+    }
+
+    // Copy `bytes` into the next transient area and return their address.
+    // An area is only allotted again when the bytes do not fit, and then
+    // with room to spare, so repeated strings do not use up data space.
+    pub(crate) fn store_transient(&mut self, bytes: &[u8]) -> std::result::Result<Value, Error> {
+        let index = self.next_transient;
+        self.next_transient = (index + 1) % TRANSIENT_STRINGS;
+        let addr = match self.transient[index] {
+            Some((addr, size)) if size >= bytes.len() => addr,
+            _ => {
+                let size = bytes.len().next_power_of_two();
+                let len = Value::try_from(size).map_err(|_| Error::DataSpaceOverflow)?;
+                let addr = self.allot(len)?;
+                self.transient[index] = Some((addr, size));
+                addr
+            }
+        };
+        self.get_mut(addr, bytes.len() as Value)?
+            .copy_from_slice(bytes);
+        Ok(addr)
+    }
+
+    fn store(&mut self, bytes: &[u8]) -> std::result::Result<Value, Error> {
+        let addr = self.allot(bytes.len() as Value)?;
+        self.bytes[addr as usize..].copy_from_slice(bytes);
+        Ok(addr)
+    }
+
+    fn get(&self, addr: Value, len: Value) -> std::result::Result<&[u8], Error> {
+        let range = self.range(addr, len)?;
+        Ok(&self.bytes[range])
+    }
+
+    fn get_mut(&mut self, addr: Value, len: Value) -> std::result::Result<&mut [u8], Error> {
+        let range = self.range(addr, len)?;
+        Ok(&mut self.bytes[range])
+    }
+
+    fn range(&self, addr: Value, len: Value) -> std::result::Result<std::ops::Range<usize>, Error> {
+        let start = usize::try_from(addr).map_err(|_| Error::InvalidAddress)?;
+        let len = usize::try_from(len).map_err(|_| Error::InvalidAddress)?;
+        match start.checked_add(len) {
+            Some(end) if end <= self.bytes.len() => Ok(start..end),
+            _ => Err(Error::InvalidAddress),
+        }
+    }
+}
+
+impl Forth {
+    /// Copy `text` into data space and push its address and length in bytes.
+    ///
+    /// The text is kept in a transient area, as are strings from `S"` outside
+    /// definitions, and is overwritten once eight more such strings are made.
+    pub fn push_str(&mut self, text: &str) -> Result {
+        let addr = Arc::make_mut(&mut self.memory).store_transient(text.as_bytes())?;
+        self.data.extend([addr, text.len() as Value]);
+        Ok(())
+    }
+
+    /// Pop an address and length and read the bytes there as text. Invalid
+    /// UTF-8 is replaced; on error the stack is left alone.
+    pub fn pop_string(&mut self) -> std::result::Result<String, Error> {
+        let [addr, len] = self.peek()?;
+        let text = String::from_utf8_lossy(self.memory.get(addr, len)?).into_owned();
+        self.replace(2, &[]);
+        Ok(text)
+    }
+
+    // The string and memory words. Each checks its arguments before taking
+    // them, so a failure leaves the stack as it was.
+    pub(super) fn execute_memory(&mut self, op: &Op) -> Result {
+        match op {
+            Op::String(addr, text) => self.data.extend([*addr, text.len() as Value]),
+            Op::CFetch => {
+                let [addr] = self.peek()?;
+                let byte = self.memory.get(addr, 1)?[0];
+                self.replace(1, &[Value::from(byte)]);
+            }
+            Op::CStore => {
+                let [val, addr] = self.peek()?;
+                Arc::make_mut(&mut self.memory).get_mut(addr, 1)?[0] = val as u8;
+                self.replace(2, &[]);
+            }
+            Op::Type => {
+                let [addr, len] = self.peek()?;
+                let text = String::from_utf8_lossy(self.memory.get(addr, len)?);
+                self.output.push_str(&text);
+                self.replace(2, &[]);
+            }
+            Op::Count => {
+                let [addr] = self.peek()?;
+                let len = self.memory.get(addr, 1)?[0];
+                self.replace(1, &[addr + 1, Value::from(len)]);
+            }
+            Op::Compare => {
+                let [addr1, len1, addr2, len2] = self.peek()?;
+                let order = self
+                    .memory
+                    .get(addr1, len1)?
+                    .cmp(self.memory.get(addr2, len2)?);
+                self.replace(4, &[order as Value]);
+            }
+            Op::Search => {
+                let [addr1, len1, addr2, len2] = self.peek()?;
+                let haystack = self.memory.get(addr1, len1)?;
+                let needle = self.memory.get(addr2, len2)?;
+                let found = if needle.is_empty() {
+                    Some(0)
+                } else {
+                    haystack
+                        .windows(needle.len())
+                        .position(|window| window == needle)
+                };
+                let result = match found {
+                    Some(offset) => {
+                        let offset = offset as Value;
+                        [addr1 + offset, len1 - offset, flag(true)]
+                    }
+                    None => [addr1, len1, flag(false)],
+                };
+                self.replace(4, &result);
+            }
+            Op::SlashString => {
+                let [addr, len, n] = self.peek()?;
+                let (Some(addr), Some(len)) = (addr.checked_add(n), len.checked_sub(n)) else {
+                    return Err(Error::InvalidAddress);
+                };
+                self.replace(3, &[addr, len]);
+            }
+            Op::CMove => {
+                let [from, to, len] = self.peek()?;
+                let from = self.memory.range(from, len)?;
+                let to = self.memory.range(to, len)?;
+                // One byte at a time from the start, so that overlapping
+                // moves propagate the way they do in other Forths.
+                let bytes = &mut Arc::make_mut(&mut self.memory).bytes;
+                for (from, to) in from.zip(to) {
+                    bytes[to] = bytes[from];
+                }
+                self.replace(3, &[]);
+            }
+            _ => unreachable!("not a memory word"),
+        }
+        Ok(())
+    }
+}
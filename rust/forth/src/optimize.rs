@@ -0,0 +1,115 @@
+use crate::{Forth, Op, Value, flag};
+
+// Calls to words with at most this many ops are replaced by the ops.
+const INLINE_LIMIT: usize = 4;
+
+impl Forth {
+    /// Optimize definitions compiled from now on: fold constant arithmetic,
+    /// remove redundant stack shuffles and inline small words.
+    ///
+    /// Optimized code fails with the same errors as the original, but
+    /// inlined words are no longer seen by breakpoints or `SEE`. Words that
+    /// use `IF` are left as written.
+    pub fn set_optimize(&mut self, enabled: bool) {
+        self.optimize = enabled;
+    }
+}
+
+pub(super) fn optimize(body: Vec<Op>) -> Vec<Op> {
+    // Rewriting would move the targets of branches
+    if body.iter().any(Op::is_branch) {
+        return body;
+    }
+
+    let mut out = Vec::with_capacity(body.len());
+    for op in body {
+        emit(&mut out, op, true);
+    }
+    out
+}
+
+// Append `op`, then keep rewriting the end of `out` while a rule applies.
+fn emit(out: &mut Vec<Op>, op: Op, inline: bool) {
+    if let Op::Word(word) = &op
+        && inline
+        && word.body.len() <= INLINE_LIMIT
+        && !word.body.iter().any(|op| {
+            op.is_branch()
+                || matches!(
+                    op,
+                    Op::Recurse | Op::Locals(_) | Op::Local(..) | Op::ToLocal(..)
+                )
+        })
+    {
+        // The body was optimized when it was defined; inlining only one
+        // level keeps the growth of nested definitions bounded.
+        for inner in word.body.iter() {
+            emit(out, inner.clone(), false);
+        }
+        return;
+    }
+
+    out.push(op);
+    if let Some(replacement) = rewrite(out) {
+        for op in replacement {
+            emit(out, op, false);
+        }
+    }
+}
+
+// Try the peephole rules against the end of `out`. On a match the matched
+// ops are removed and their replacement returned for re-emitting.
+fn rewrite(out: &mut Vec<Op>) -> Option<Vec<Op>> {
+    use Op::*;
+
+    let (matched, replacement) = match out.as_slice() {
+        [
+            ..,
+            Push(a),
+            Push(b),
+            op @ (Add | Sub | Mul | Div | Equal | Less | Greater),
+        ] => {
+            // Leave failing arithmetic to raise its error at run time
+            (3, vec![Push(fold(op, *a, *b)?)])
+        }
+        [.., Push(a), Push(b), Swap] => (3, vec![Push(*b), Push(*a)]),
+        [.., Push(a), Push(b), Over] => (3, vec![Push(*a), Push(*b), Push(*a)]),
+        [.., Push(a), Dup] => (2, vec![Push(*a), Push(*a)]),
+        [.., Push(_), Drop] => (2, vec![]),
+        // These pairs change nothing when they succeed, but still have to
+        // fail on a short stack, and a failing `SWAP` or `OVER` drops the
+        // cell it popped.
+        [.., Dup, Drop] => (2, vec![Need(1)]),
+        [.., Swap, Swap] | [.., Over, Drop] => (2, vec![NeedOrDrop(2)]),
+        [.., Need(a), Need(b)] => (2, vec![Need(*a.max(b))]),
+        [.., Push(val), Need(1)] => (2, vec![Push(*val)]),
+        [.., Need(depth), op] if checks_depth(op, *depth) => (2, vec![op.clone()]),
+        _ => return None,
+    };
+    out.truncate(out.len() - matched);
+    Some(replacement)
+}
+
+fn fold(op: &Op, a: Value, b: Value) -> Option<Value> {
+    match op {
+        Op::Add => a.checked_add(b),
+        Op::Sub => a.checked_sub(b),
+        Op::Mul => a.checked_mul(b),
+        Op::Div => a.checked_div(b),
+        Op::Equal => Some(flag(a == b)),
+        Op::Less => Some(flag(a < b)),
+        Op::Greater => Some(flag(a > b)),
+        _ => None,
+    }
+}
+
+// Whether `op` already fails, leaving the stack untouched, whenever fewer
+// than `depth` cells are available.
+fn checks_depth(op: &Op, depth: usize) -> bool {
+    use Op::*;
+
+    matches!(
+        op,
+        Add | Sub | Mul | Div | Equal | Less | Greater | Dup | Drop
+    ) && op.effect().is_some_and(|effect| effect.inputs >= depth)
+}
@@ -0,0 +1,59 @@
+/// Settings chosen when an interpreter is made with [`Forth::with_options`].
+///
+/// [`Forth::with_options`]: crate::Forth::with_options
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    pub case: CaseFolding,
+    /// Forbid definitions named after built-in and parsing words such as
+    /// `+`, `dup` or `see`; defining one fails with `InvalidWord`.
+    pub reserve_builtins: bool,
+    pub backend: Backend,
+}
+
+/// How word names are compared.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CaseFolding {
+    /// Names must match exactly, and built-ins are written in lowercase.
+    Sensitive,
+    /// Only ASCII letters are folded, so `DUP` is `dup` but `ÄRGER` is not
+    /// `ärger`.
+    Ascii,
+    /// All letters are folded with Unicode's lowercase mapping.
+    #[default]
+    Unicode,
+}
+
+impl CaseFolding {
+    // Write the folded form of `token` into `folded`, reusing its
+    // allocation so that looking up a token does not allocate.
+    pub(crate) fn fold(self, token: &str, folded: &mut String) {
+        folded.clear();
+        match self {
+            CaseFolding::Sensitive => folded.push_str(token),
+            CaseFolding::Ascii => folded.extend(token.chars().map(|c| c.to_ascii_lowercase())),
+            CaseFolding::Unicode => folded.extend(token.chars().flat_map(char::to_lowercase)),
+        }
+    }
+
+    // Whether `token` names the built-in or parsing word `word`, which is
+    // lowercase ASCII.
+    pub(crate) fn matches(self, token: &str, word: &str) -> bool {
+        match self {
+            CaseFolding::Sensitive => token == word,
+            CaseFolding::Ascii | CaseFolding::Unicode => token.eq_ignore_ascii_case(word),
+        }
+    }
+}
+
+/// How user definitions are executed. Both give the same results and
+/// errors; `cargo bench --bench backends` compares their speed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Backend {
+    /// Interpret each compiled op with a `match`.
+    #[default]
+    Interpreter,
+    /// Compile each definition to a chain of boxed closures when it is
+    /// defined, and run those. A tracer still sees every op, as it runs
+    /// through the interpreter.
+    Closures,
+}
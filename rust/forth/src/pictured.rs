@@ -0,0 +1,108 @@
+use std::fmt::Write;
+use std::sync::Arc;
+
+use crate::double::{join, split};
+use crate::memory::HOLD_SIZE;
+use crate::{Double, Error, Forth, Op, Result, Value};
+
+impl Forth {
+    // Pictured numeric output and the number printing words. The number
+    // being converted is an unsigned double, and digits are held from the
+    // right, so `hold` keeps the text reversed until `#>`.
+    pub(super) fn execute_pictured(&mut self, op: &Op) -> Result {
+        match op {
+            Op::LessNumberSign => self.hold.clear(),
+            Op::NumberSign => {
+                let [low, high] = self.peek()?;
+                let rest = self.hold_digit(join(low, high) as u64)?;
+                self.replace(2, &split(rest as Double));
+            }
+            Op::NumberSignS => {
+                let [low, high] = self.peek()?;
+                let mut rest = join(low, high) as u64;
+                let held = self.hold.len();
+                loop {
+                    match self.hold_digit(rest) {
+                        Ok(0) => break,
+                        Ok(next) => rest = next,
+                        Err(error) => {
+                            self.hold.truncate(held);
+                            return Err(error);
+                        }
+                    }
+                }
+                self.replace(2, &[0, 0]);
+            }
+            Op::Hold => {
+                let [char] = self.peek()?;
+                self.hold_char(char as u8)?;
+                self.replace(1, &[]);
+            }
+            Op::Sign => {
+                let [n] = self.peek()?;
+                if n < 0 {
+                    self.hold_char(b'-')?;
+                }
+                self.replace(1, &[]);
+            }
+            Op::NumberSignGreater => {
+                self.peek::<2>()?;
+                let text: Vec<u8> = self.hold.iter().rev().copied().collect();
+                let addr = Arc::make_mut(&mut self.memory).store_hold(&text)?;
+                self.replace(2, &[addr, text.len() as Value]);
+            }
+            Op::Dot => {
+                let [n] = self.peek()?;
+                write!(self.output, "{n} ").unwrap();
+                self.replace(1, &[]);
+            }
+            Op::UDot => {
+                let [u] = self.peek()?;
+                write!(self.output, "{} ", u as u32).unwrap();
+                self.replace(1, &[]);
+            }
+            Op::DotR => {
+                let [n, width] = self.peek()?;
+                let width = field_width(width)?;
+                write!(self.output, "{n:>width$}").unwrap();
+                self.replace(2, &[]);
+            }
+            Op::UDotR => {
+                let [u, width] = self.peek()?;
+                let width = field_width(width)?;
+                write!(self.output, "{:>width$}", u as u32).unwrap();
+                self.replace(2, &[]);
+            }
+            _ => unreachable!("not a number output word"),
+        }
+        Ok(())
+    }
+
+    // Hold the lowest decimal digit of `number` and return the rest.
+    fn hold_digit(&mut self, number: u64) -> std::result::Result<u64, Error> {
+        self.hold_char(b'0' + (number % 10) as u8)?;
+        Ok(number / 10)
+    }
+
+    fn hold_char(&mut self, char: u8) -> Result {
+        if self.hold.len() == HOLD_SIZE {
+            return Err(Error::DataSpaceOverflow);
+        }
+        self.hold.push(char);
+        Ok(())
+    }
+}
+
+// The widest field `.R` and `U.R` will pad to. It is far wider than any
+// report column, so a huge width is taken as a mistake rather than padded,
+// and stays within what `write!` accepts.
+const MAX_FIELD_WIDTH: usize = 4096;
+
+// The width of a right-aligned field; negative widths are treated as zero.
+fn field_width(width: Value) -> std::result::Result<usize, Error> {
+    let width = width.max(0) as usize;
+    if width > MAX_FIELD_WIDTH {
+        return Err(Error::InvalidWidth);
+    }
+    Ok(width)
+}
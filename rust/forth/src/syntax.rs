@@ -0,0 +1,165 @@
+use crate::CaseFolding;
+use crate::token::Tokenizer;
+
+// Source split up the way the outer interpreter reads it, for the
+// formatter and linter. Nothing is looked up or compiled, so any text can
+// be read.
+
+// What a parsing word reads after it.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Parses {
+    // Nothing; the word is handled where it appears.
+    Nothing,
+    // A name, and a definition body up to `;`.
+    Definition,
+    // Text up to a closing character.
+    Until(char),
+    // Words up to a closing word.
+    Words(&'static str),
+    // The name of a word that exists.
+    Word,
+    // The name of a new word.
+    NewWord,
+    // The name of a file.
+    File,
+}
+
+// Words handled by the outer interpreter rather than compiled, what each
+// reads, and whether it only has a meaning inside a definition.
+pub(crate) const PARSING_WORDS: [(&str, Parses, bool); 18] = [
+    (":", Parses::Definition, false),
+    (";", Parses::Nothing, false),
+    ("(", Parses::Until(')'), false),
+    ("s\"", Parses::Until('"'), false),
+    ("see", Parses::Word, false),
+    ("vocabulary", Parses::NewWord, false),
+    ("marker", Parses::NewWord, false),
+    ("forget", Parses::Word, false),
+    ("buffer:", Parses::NewWord, false),
+    ("include", Parses::File, false),
+    ("require", Parses::File, false),
+    ("if", Parses::Nothing, true),
+    ("else", Parses::Nothing, true),
+    ("then", Parses::Nothing, true),
+    ("recurse", Parses::Nothing, true),
+    ("recursive", Parses::Nothing, true),
+    ("{:", Parses::Words(":}"), true),
+    ("to", Parses::Word, true),
+];
+
+// The entry in `PARSING_WORDS` for `token`, if it is a parsing word.
+pub(crate) fn parsing_word(token: &str, case: CaseFolding) -> Option<(&'static str, Parses, bool)> {
+    PARSING_WORDS
+        .into_iter()
+        .find(|(word, ..)| case.matches(token, word))
+}
+
+// One word of source, with the raw text a parsing word takes after it.
+pub(crate) enum Token<'a> {
+    Word(&'a str),
+    // The text of a `( ...)` comment.
+    Comment(&'a str),
+    // `s"` as written, and the text of the string.
+    String(&'a str, &'a str),
+    // The words of a `{: ... :}` locals declaration, between the braces.
+    Locals(Vec<&'a str>),
+    // A parsing word such as `see` or `include`, and the name it takes.
+    Parsing(&'a str, &'a str),
+}
+
+pub(crate) enum Item<'a> {
+    Definition(Definition<'a>),
+    Token(Token<'a>),
+}
+
+impl<'a> Item<'a> {
+    // The name given to a new word by a definition or defining word.
+    pub(crate) fn defined_name(&self) -> Option<&'a str> {
+        match self {
+            Item::Definition(definition) => Some(definition.name),
+            Item::Token(Token::Parsing(word, name))
+                if matches!(parsing_word(word, CASE), Some((_, Parses::NewWord, _))) =>
+            {
+                Some(name)
+            }
+            _ => None,
+        }
+    }
+}
+
+pub(crate) struct Definition<'a> {
+    pub(crate) name: &'a str,
+    pub(crate) body: Vec<Token<'a>>,
+    // Whether the definition ends with `;` rather than the end of source.
+    pub(crate) closed: bool,
+}
+
+// An item and the lines, counting from 1, that it starts and ends on.
+pub(crate) struct Spanned<'a> {
+    pub(crate) item: Item<'a>,
+    pub(crate) start: usize,
+    pub(crate) end: usize,
+}
+
+pub(crate) fn parse(source: &str) -> Vec<Spanned<'_>> {
+    let mut tokens = Tokenizer::new(source);
+    let mut items = Vec::new();
+    while let Some(word) = tokens.next() {
+        let start = tokens.line();
+        let item = match parsing_word(word, CASE) {
+            Some((_, Parses::Definition, _)) => match tokens.next() {
+                Some(name) => Item::Definition(parse_definition(name, &mut tokens)),
+                None => Item::Token(Token::Word(word)),
+            },
+            _ => Item::Token(parse_token(word, &mut tokens)),
+        };
+        items.push(Spanned {
+            item,
+            start,
+            end: tokens.line(),
+        });
+    }
+    items
+}
+
+fn parse_definition<'a>(name: &'a str, tokens: &mut Tokenizer<'a>) -> Definition<'a> {
+    let mut body = Vec::new();
+    while let Some(word) = tokens.next() {
+        if word == ";" {
+            return Definition {
+                name,
+                body,
+                closed: true,
+            };
+        }
+        body.push(parse_token(word, tokens));
+    }
+    Definition {
+        name,
+        body,
+        closed: false,
+    }
+}
+
+fn parse_token<'a>(word: &'a str, tokens: &mut Tokenizer<'a>) -> Token<'a> {
+    match parsing_word(word, CASE).map(|(_, parses, _)| parses) {
+        Some(Parses::Until(')')) => Token::Comment(tokens.parse_until(')')),
+        Some(Parses::Until(end)) => Token::String(word, tokens.parse_until(end)),
+        Some(Parses::Words(end)) => {
+            Token::Locals(tokens.by_ref().take_while(|&word| word != end).collect())
+        }
+        Some(Parses::Word | Parses::NewWord | Parses::File) => match tokens.next() {
+            Some(name) => Token::Parsing(word, name),
+            None => Token::Word(word),
+        },
+        _ => Token::Word(word),
+    }
+}
+
+// Parsing words are matched as with the default options.
+const CASE: CaseFolding = CaseFolding::Unicode;
+
+// Whether `word` is the built-in or parsing word `name`.
+pub(crate) fn is(word: &str, name: &str) -> bool {
+    CASE.matches(word, name)
+}
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::{Error, Forth, Frame, Program, Value, Word};
+
+/// Identifies a task made by [`Forth::spawn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TaskId(u32);
+
+/// What a task did when it was last given a turn by [`Forth::run_slice`].
+#[derive(Debug, PartialEq, Eq)]
+pub enum Status {
+    /// It ran `PAUSE` and is waiting for its next turn.
+    Paused,
+    /// It ran to the end, leaving this stack.
+    Finished(Vec<Value>),
+    Failed(Error),
+}
+
+// The state a task keeps while other tasks run.
+pub(crate) struct Task {
+    id: TaskId,
+    data: Vec<Value>,
+    frames: Vec<Frame>,
+    locals: Vec<Value>,
+}
+
+impl Forth {
+    /// Start a task that runs `program` with its own data and return
+    /// stacks. It shares the dictionary, data space and output with the
+    /// interpreter, and only runs when the host calls [`Forth::run_slice`].
+    pub fn spawn(&mut self, program: &Program) -> TaskId {
+        let id = TaskId(self.next_task);
+        self.next_task += 1;
+        let word = Word {
+            name: "task".into(),
+            code: self.compile_code(&program.ops),
+            body: program.ops.iter().cloned().collect(),
+            effect: None,
+        };
+        self.tasks.push_back(Task {
+            id,
+            data: Vec::new(),
+            frames: vec![Frame {
+                word: Arc::new(word),
+                ip: 0,
+                locals: 0,
+            }],
+            locals: Vec::new(),
+        });
+        id
+    }
+
+    /// How many tasks have not yet finished or failed.
+    pub fn task_count(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// The data stack of a task waiting for its turn.
+    pub fn task_stack(&self, id: TaskId) -> Option<&[Value]> {
+        let task = self.tasks.iter().find(|task| task.id == id)?;
+        Some(&task.data)
+    }
+
+    /// Give the next task, in round-robin order, a turn: run it until it
+    /// runs `PAUSE`, finishes or fails. Returns `None` if there are no tasks.
+    pub fn run_slice(&mut self) -> Option<(TaskId, Status)> {
+        let mut task = self.tasks.pop_front()?;
+        self.swap_task(&mut task);
+        self.in_task = true;
+        let result = self.execute_frames(0);
+        self.in_task = false;
+        let paused = std::mem::take(&mut self.pausing);
+        self.swap_task(&mut task);
+
+        let id = task.id;
+        let status = match result {
+            Err(error) => Status::Failed(error),
+            Ok(()) if paused => {
+                self.tasks.push_back(task);
+                Status::Paused
+            }
+            Ok(()) => Status::Finished(task.data),
+        };
+        Some((id, status))
+    }
+
+    fn swap_task(&mut self, task: &mut Task) {
+        std::mem::swap(&mut self.data, &mut task.data);
+        std::mem::swap(&mut self.frames, &mut task.frames);
+        std::mem::swap(&mut self.locals, &mut task.locals);
+    }
+
+    // `PAUSE` stops the running task after the current instruction; outside
+    // a task there is nothing to switch to.
+    pub(super) fn pause(&mut self) {
+        self.pausing = self.in_task;
+    }
+}
@@ -0,0 +1,53 @@
+/// Splits source text into whitespace separated words, and lets parsing
+/// words such as `(` take raw text up to a delimiter.
+pub(crate) struct Tokenizer<'a> {
+    source: &'a str,
+    rest: &'a str,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub(crate) fn new(source: &'a str) -> Self {
+        Tokenizer {
+            source,
+            rest: source,
+        }
+    }
+
+    // The line, counting from 1, of the last word or text taken.
+    pub(crate) fn line(&self) -> usize {
+        let taken = &self.source[..self.source.len() - self.rest.len()];
+        taken.trim_end().matches('\n').count() + 1
+    }
+
+    // Take the text up to `delimiter`, skipping the single space that ended
+    // the previous word. Without a delimiter the rest of the source is taken.
+    pub(crate) fn parse_until(&mut self, delimiter: char) -> &'a str {
+        let mut chars = self.rest.chars();
+        if chars.next().is_some_and(char::is_whitespace) {
+            self.rest = chars.as_str();
+        }
+        match self.rest.split_once(delimiter) {
+            Some((text, rest)) => {
+                self.rest = rest;
+                text
+            }
+            None => std::mem::take(&mut self.rest),
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let start = self.rest.trim_start();
+        if start.is_empty() {
+            self.rest = start;
+            return None;
+        }
+        let end = start.find(char::is_whitespace).unwrap_or(start.len());
+        let (token, rest) = start.split_at(end);
+        self.rest = rest;
+        Some(token)
+    }
+}
@@ -0,0 +1,33 @@
+/* Built and run by `tests/ffi.rs` against `include/forth.h`. */
+
+#include <stdio.h>
+#include <string.h>
+
+#include "forth.h"
+
+int main(void) {
+    ForthHandle *forth = forth_new();
+
+    if (forth_eval(forth, ": sq dup * ; 3 sq 4") != FORTH_OK) {
+        return 1;
+    }
+    size_t len = forth_stack_len(forth);
+    const forth_value_t *stack = forth_stack(forth);
+    if (len != 2 || stack[0] != 9 || stack[1] != 4) {
+        return 2;
+    }
+    if (forth_error_message(forth) != NULL) {
+        return 3;
+    }
+
+    if (forth_eval(forth, "1 0 /") != FORTH_DIVISION_BY_ZERO) {
+        return 4;
+    }
+    if (strcmp(forth_error_message(forth), "division by zero") != 0) {
+        return 5;
+    }
+
+    forth_free(forth);
+    puts("ok");
+    return 0;
+}
@@ -0,0 +1,122 @@
+use forth::*;
+
+#[test]
+fn call_returns_the_values_a_word_leaves() {
+    let mut f = Forth::new();
+    assert!(f.eval(": price ( qty unit -- total ) * 5 + ;").is_ok());
+    let [total] = f.call("price", &[3, 4]).unwrap();
+    assert_eq!(total, 17);
+    assert_eq!(f.call("PRICE", &[10, 2]), Ok([25]));
+}
+
+#[test]
+fn call_leaves_the_main_stack_alone() {
+    let mut f = Forth::new();
+    assert!(f.eval(": split dup 2 / swap over - ; 1 2 3").is_ok());
+    assert_eq!(f.call("split", &[7]), Ok([3, 4]));
+    assert_eq!(f.stack(), [1, 2, 3]);
+}
+
+#[test]
+fn builtins_can_be_called() {
+    let mut f = Forth::new();
+    assert_eq!(f.call("-", &[10, 3]), Ok([7]));
+    assert_eq!(f.call("over", &[1, 2]), Ok([1, 2, 1]));
+}
+
+#[test]
+fn mismatched_cell_counts_are_rejected_before_running() {
+    let mut f = Forth::new();
+    assert!(f.eval(": price * ;").is_ok());
+    assert_eq!(f.call::<1>("price", &[3]), Err(Error::StackEffectMismatch));
+    assert_eq!(
+        f.call::<2>("price", &[3, 4]),
+        Err(Error::StackEffectMismatch)
+    );
+}
+
+#[test]
+fn results_are_checked_when_the_effect_is_unknown() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": maybe-double dup 0 < if drop else dup then ;")
+            .is_ok()
+    );
+    assert_eq!(f.stack_effect("maybe-double"), None);
+    assert_eq!(f.call("maybe-double", &[4]), Ok([4, 4]));
+    assert_eq!(
+        f.call::<2>("maybe-double", &[-4]),
+        Err(Error::StackEffectMismatch)
+    );
+}
+
+#[test]
+fn every_argument_must_be_used() {
+    let mut f = Forth::new();
+    assert!(f.eval(": weird dup 0 < if drop then ;").is_ok());
+    assert_eq!(
+        f.call::<2>("weird", &[1, 5]),
+        Err(Error::StackEffectMismatch)
+    );
+    assert_eq!(f.call::<0>("weird", &[-5]), Ok([]));
+
+    let mut f = Forth::with_options(Options {
+        backend: Backend::Closures,
+        ..Options::default()
+    });
+    assert!(f.eval(": weird dup 0 < if drop then ;").is_ok());
+    assert_eq!(
+        f.call::<2>("weird", &[1, 5]),
+        Err(Error::StackEffectMismatch)
+    );
+}
+
+#[test]
+fn words_of_known_effect_need_not_read_every_argument() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": second ( a b -- a ) drop ; : keep ( a b -- a b ) ;")
+            .is_ok()
+    );
+    assert_eq!(f.call::<1>("second", &[1, 2]), Ok([1]));
+    assert_eq!(f.call::<2>("keep", &[1, 2]), Ok([1, 2]));
+}
+
+#[test]
+fn calls_agree_between_backends() {
+    let source = ": second ( a b -- a ) drop ; : weird dup 0 < if drop then ;";
+    let mut plain = Forth::new();
+    let mut fast = Forth::with_options(Options {
+        backend: Backend::Closures,
+        ..Options::default()
+    });
+    for f in [&mut plain, &mut fast] {
+        assert!(f.eval(source).is_ok());
+    }
+    assert_eq!(plain.call::<1>("second", &[1, 2]), Ok([1]));
+    assert_eq!(fast.call::<1>("second", &[1, 2]), Ok([1]));
+    assert_eq!(
+        plain.call::<0>("weird", &[-5]),
+        fast.call::<0>("weird", &[-5])
+    );
+    assert_eq!(
+        plain.call::<2>("weird", &[1, 5]),
+        fast.call::<2>("weird", &[1, 5])
+    );
+}
+
+#[test]
+fn declared_effects_are_checked_at_run_time() {
+    let mut f = Forth::new();
+    assert!(f.eval(": liar ( n -- n ) if 1 2 then ;").is_ok());
+    assert_eq!(f.call::<1>("liar", &[-1]), Err(Error::StackEffectMismatch));
+}
+
+#[test]
+fn errors_come_from_the_word() {
+    let mut f = Forth::new();
+    assert!(f.eval(": ratio / ; 9").is_ok());
+    assert_eq!(f.call::<1>("ratio", &[1, 0]), Err(Error::DivisionByZero));
+    assert_eq!(f.call::<0>("missing", &[]), Err(Error::UnknownWord));
+    assert_eq!(f.stack(), [9]);
+}
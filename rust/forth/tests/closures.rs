@@ -0,0 +1,84 @@
+use std::cell::Cell;
+use std::rc::Rc;
+
+use forth::*;
+
+mod common;
+
+fn with_backend(backend: Backend) -> Forth {
+    Forth::with_options(Options {
+        backend,
+        ..Options::default()
+    })
+}
+
+// Run each input on both backends in turn, checking that every result and
+// stack agree.
+fn assert_agree(inputs: &[&str]) {
+    let mut interpreter = with_backend(Backend::Interpreter);
+    let mut closures = with_backend(Backend::Closures);
+    for input in inputs {
+        assert_eq!(interpreter.eval(input), closures.eval(input), "{input}");
+        assert_eq!(interpreter.stack(), closures.stack(), "{input}");
+        assert_eq!(interpreter.output(), closures.output(), "{input}");
+    }
+}
+
+#[test]
+fn the_upstream_suite_agrees() {
+    for (name, inputs) in common::SUITE {
+        println!("{name}");
+        assert_agree(inputs);
+    }
+}
+
+#[test]
+fn control_flow_and_locals_agree() {
+    assert_agree(&[
+        ": fact {: n :} n 1 > if n 1 - recurse n * else 1 then ;",
+        ": fib recursive dup 1 > if dup 1 - fib swap 2 - fib + then ;",
+        "10 fact 12 fib",
+        "13 fact",
+        ": countdown dup if 1 - recurse then ; 100000 countdown",
+        ": forever recurse 1 ; forever",
+        ": pair {: a b :} a b ; 1 pair",
+    ]);
+}
+
+#[test]
+fn strings_doubles_and_vocabularies_agree() {
+    assert_agree(&[
+        ": greet s\" hi\" type ; greet",
+        ": big 3000000000. 1. d+ ; big d. big d>s",
+        "vocabulary v also v definitions : greet 5 ; greet previous greet",
+        "marker undo : x 1 ; undo x",
+    ]);
+}
+
+#[test]
+fn tracers_still_see_every_instruction() {
+    struct Count(Rc<Cell<usize>>);
+    impl Tracer for Count {
+        fn before(&mut self, _event: &Event<'_>) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    let seen = Rc::default();
+    let mut f = with_backend(Backend::Closures);
+    assert!(f.eval(": sq dup * ;").is_ok());
+    f.set_tracer(Count(Rc::clone(&seen)));
+    assert!(f.eval("3 sq").is_ok());
+    assert_eq!(f.stack(), [9]);
+    assert_eq!(seen.get(), 3);
+}
+
+#[test]
+fn tasks_pause_in_compiled_words() {
+    let mut f = with_backend(Backend::Closures);
+    assert!(f.eval(": twice dup pause + ;").is_ok());
+    let program = f.compile("21 twice").unwrap();
+    let task = f.spawn(&program);
+    assert_eq!(f.run_slice(), Some((task, Status::Paused)));
+    assert_eq!(f.run_slice(), Some((task, Status::Finished(vec![42]))));
+}
@@ -0,0 +1,177 @@
+// Inputs shared by tests that check other ways of running code against the
+// upstream suite in `forth.rs`: the strings each of its tests passes to
+// `eval`, in order, by test name.
+pub const SUITE: &[(&str, &[&str])] = &[
+    (
+        "parsing_and_numbers::numbers_just_get_pushed_onto_the_stack",
+        &["1 2 3 4 5"],
+    ),
+    (
+        "parsing_and_numbers::pushes_negative_numbers_onto_the_stack",
+        &["-1 -2 -3 -4 -5"],
+    ),
+    ("addition::can_add_two_numbers", &["1 2 +"]),
+    ("addition::errors_if_there_is_nothing_on_the_stack", &["+"]),
+    (
+        "addition::errors_if_there_is_only_one_value_on_the_stack",
+        &["1 +"],
+    ),
+    ("addition::more_than_two_values_on_the_stack", &["1 2 3 +"]),
+    ("subtraction::can_subtract_two_numbers", &["3 4 -"]),
+    (
+        "subtraction::errors_if_there_is_nothing_on_the_stack",
+        &["-"],
+    ),
+    (
+        "subtraction::errors_if_there_is_only_one_value_on_the_stack",
+        &["1 -"],
+    ),
+    (
+        "subtraction::more_than_two_values_on_the_stack",
+        &["1 12 3 -"],
+    ),
+    ("multiplication::can_multiply_two_numbers", &["2 4 *"]),
+    (
+        "multiplication::errors_if_there_is_nothing_on_the_stack",
+        &["*"],
+    ),
+    (
+        "multiplication::errors_if_there_is_only_one_value_on_the_stack",
+        &["1 *"],
+    ),
+    (
+        "multiplication::more_than_two_values_on_the_stack",
+        &["1 2 3 *"],
+    ),
+    ("division::can_divide_two_numbers", &["12 3 /"]),
+    ("division::performs_integer_division", &["8 3 /"]),
+    ("division::errors_if_dividing_by_zero", &["4 0 /"]),
+    ("division::errors_if_there_is_nothing_on_the_stack", &["/"]),
+    (
+        "division::errors_if_there_is_only_one_value_on_the_stack",
+        &["1 /"],
+    ),
+    ("division::more_than_two_values_on_the_stack", &["1 12 3 /"]),
+    (
+        "combined_arithmetic::addition_and_subtraction",
+        &["1 2 + 4 -"],
+    ),
+    (
+        "combined_arithmetic::multiplication_and_division",
+        &["2 4 * 3 /"],
+    ),
+    (
+        "combined_arithmetic::multiplication_and_addition",
+        &["1 3 4 * +"],
+    ),
+    (
+        "combined_arithmetic::addition_and_multiplication",
+        &["1 3 4 + *"],
+    ),
+    ("dup::copies_a_value_on_the_stack", &["1 dup"]),
+    ("dup::copies_the_top_value_on_the_stack", &["1 2 dup"]),
+    ("dup::errors_if_there_is_nothing_on_the_stack", &["dup"]),
+    (
+        "drop::removes_the_top_value_on_the_stack_if_it_is_the_only_one",
+        &["1 drop"],
+    ),
+    (
+        "drop::removes_the_top_value_on_the_stack_if_it_is_not_the_only_one",
+        &["1 2 drop"],
+    ),
+    ("drop::errors_if_there_is_nothing_on_the_stack", &["drop"]),
+    (
+        "swap::swaps_the_top_two_values_on_the_stack_if_they_are_the_only_ones",
+        &["1 2 swap"],
+    ),
+    (
+        "swap::swaps_the_top_two_values_on_the_stack_if_they_are_not_the_only_ones",
+        &["1 2 3 swap"],
+    ),
+    ("swap::errors_if_there_is_nothing_on_the_stack", &["swap"]),
+    (
+        "swap::errors_if_there_is_only_one_value_on_the_stack",
+        &["1 swap"],
+    ),
+    (
+        "over::copies_the_second_element_if_there_are_only_two",
+        &["1 2 over"],
+    ),
+    (
+        "over::copies_the_second_element_if_there_are_more_than_two",
+        &["1 2 3 over"],
+    ),
+    ("over::errors_if_there_is_nothing_on_the_stack", &["over"]),
+    (
+        "over::errors_if_there_is_only_one_value_on_the_stack",
+        &["1 over"],
+    ),
+    (
+        "user_defined_words::can_consist_of_built_in_words",
+        &[": dup-twice dup dup ;", "1 dup-twice"],
+    ),
+    (
+        "user_defined_words::execute_in_the_right_order",
+        &[": countup 1 2 3 ;", "countup"],
+    ),
+    (
+        "user_defined_words::can_override_other_user_defined_words",
+        &[": foo dup ;", ": foo dup dup ;", "1 foo"],
+    ),
+    (
+        "user_defined_words::can_override_built_in_words",
+        &[": swap dup ;", "1 swap"],
+    ),
+    (
+        "user_defined_words::can_override_built_in_operators",
+        &[": + * ;", "3 4 +"],
+    ),
+    (
+        "user_defined_words::can_use_different_words_with_the_same_name",
+        &[": foo 5 ;", ": bar foo ;", ": foo 6 ;", "bar foo"],
+    ),
+    (
+        "user_defined_words::can_define_word_that_uses_word_with_the_same_name",
+        &[": foo 10 ;", ": foo foo 1 + ;", "foo"],
+    ),
+    (
+        "user_defined_words::cannot_redefine_non_negative_numbers",
+        &[": 1 2 ;"],
+    ),
+    (
+        "user_defined_words::cannot_redefine_negative_numbers",
+        &[": -1 2 ;"],
+    ),
+    (
+        "user_defined_words::errors_if_executing_a_non_existent_word",
+        &["foo"],
+    ),
+    (
+        "user_defined_words::only_defines_locally",
+        &[": + - ;", "1 1 +", "1 1 +"],
+    ),
+    (
+        "case_insensitivity::dup_is_case_insensitive",
+        &["1 DUP Dup dup"],
+    ),
+    (
+        "case_insensitivity::drop_is_case_insensitive",
+        &["1 2 3 4 DROP Drop drop"],
+    ),
+    (
+        "case_insensitivity::swap_is_case_insensitive",
+        &["1 2 SWAP 3 Swap 4 swap"],
+    ),
+    (
+        "case_insensitivity::over_is_case_insensitive",
+        &["1 2 OVER Over over"],
+    ),
+    (
+        "case_insensitivity::user_defined_words_are_case_insensitive",
+        &[": foo dup ;", "1 FOO Foo foo"],
+    ),
+    (
+        "case_insensitivity::definitions_are_case_insensitive",
+        &[": SWAP DUP Dup dup ;", "1 swap"],
+    ),
+];
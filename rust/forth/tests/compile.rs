@@ -0,0 +1,64 @@
+use forth::*;
+
+#[test]
+fn compiled_program_can_run_repeatedly() {
+    let mut f = Forth::new();
+    let program = f.compile("1 2 + DUP").unwrap();
+    assert!(f.stack().is_empty());
+    assert!(f.run(&program).is_ok());
+    assert!(f.run(&program).is_ok());
+    assert_eq!(f.stack(), [3, 3, 3, 3]);
+}
+
+#[test]
+fn definitions_take_effect_when_compiled() {
+    let mut f = Forth::new();
+    let program = f.compile(": sq dup * ; 3 sq").unwrap();
+    assert_eq!(f.words().collect::<Vec<_>>(), ["sq"]);
+    assert!(f.run(&program).is_ok());
+    assert_eq!(f.stack(), [9]);
+}
+
+#[test]
+fn programs_keep_the_definitions_they_were_compiled_with() {
+    let mut f = Forth::new();
+    let program = f.compile(": foo 5 ; foo").unwrap();
+    assert!(f.eval(": foo 6 ;").is_ok());
+    assert!(f.run(&program).is_ok());
+    assert!(f.eval("foo").is_ok());
+    assert_eq!(f.stack(), [5, 6]);
+}
+
+#[test]
+fn unknown_words_fail_at_compile_time() {
+    let mut f = Forth::new();
+    assert_eq!(f.compile("1 2 nothing").err(), Some(Error::UnknownWord));
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn buffer_cannot_be_compiled() {
+    let mut f = Forth::new();
+    assert!(f.eval("5").is_ok());
+    assert_eq!(f.compile("10 buffer: buf").err(), Some(Error::InvalidWord));
+    assert_eq!(f.stack(), [5]);
+    assert!(f.eval("10 buffer: buf buf").is_ok());
+    assert_eq!(f.stack().len(), 2);
+}
+
+#[test]
+fn run_time_errors_are_reported_by_run() {
+    let mut f = Forth::new();
+    let program = f.compile("1 0 /").unwrap();
+    assert_eq!(f.run(&program), Err(Error::DivisionByZero));
+    assert_eq!(f.stack(), [1, 0]);
+}
+
+#[test]
+fn comments_end_at_the_closing_parenthesis() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 ( a b--c) 2 (not-a-comment-start").is_err());
+    assert_eq!(f.stack(), [1, 2]);
+    assert!(f.eval(": sq ( n--n*n) dup * ; 3 sq").is_ok());
+    assert_eq!(f.stack(), [1, 2, 9]);
+}
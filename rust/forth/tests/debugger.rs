@@ -0,0 +1,134 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use forth::*;
+
+// Records every hook call as a line of text.
+#[derive(Clone, Default)]
+struct Log {
+    lines: Rc<RefCell<Vec<String>>>,
+    resume: Vec<Resume>,
+}
+
+impl Log {
+    fn lines(&self) -> Vec<String> {
+        self.lines.borrow().clone()
+    }
+}
+
+impl Tracer for Log {
+    fn before(&mut self, event: &Event<'_>) {
+        self.lines.borrow_mut().push(format!(
+            "{} in {} {:?}",
+            event.instruction,
+            event.word.unwrap_or("-"),
+            event.stack
+        ));
+    }
+
+    fn after(&mut self, event: &Event<'_>, result: &forth::Result) {
+        if let Err(error) = result {
+            self.lines
+                .borrow_mut()
+                .push(format!("{error:?} at depth {}", event.depth));
+        }
+    }
+
+    fn on_break(&mut self, event: &Event<'_>) -> Resume {
+        self.lines
+            .borrow_mut()
+            .push(format!("break at {}", event.instruction));
+        self.resume.pop().unwrap_or(Resume::Continue)
+    }
+}
+
+#[test]
+fn tracer_sees_each_primitive_with_its_word_and_stack() {
+    let log = Log::default();
+    let mut f = Forth::new();
+    f.set_tracer(log.clone());
+    assert!(f.eval(": inc 1 + ; 5 inc").is_ok());
+    assert_eq!(
+        log.lines(),
+        ["5 in - []", "1 in inc [5]", "+ in inc [5, 1]"]
+    );
+}
+
+#[test]
+fn tracer_locates_underflow_in_nested_words() {
+    let log = Log::default();
+    let mut f = Forth::new();
+    assert!(f.eval(": inner drop drop ; : outer 1 inner ;").is_ok());
+    f.set_tracer(log.clone());
+    assert_eq!(f.eval("outer"), Err(Error::StackUnderflow));
+    assert_eq!(
+        log.lines(),
+        [
+            "1 in outer []",
+            "drop in inner [1]",
+            "drop in inner []",
+            "StackUnderflow at depth 2",
+        ]
+    );
+}
+
+#[test]
+fn breakpoint_stops_before_entering_the_word() {
+    let log = Log::default();
+    let mut f = Forth::new();
+    assert!(f.eval(": sq dup * ; : quad sq sq ;").is_ok());
+    f.set_tracer(log.clone());
+    f.set_breakpoint("SQ");
+    assert!(f.eval("2 quad").is_ok());
+    assert_eq!(f.stack(), [16]);
+    let breaks: Vec<_> = log
+        .lines()
+        .into_iter()
+        .filter(|line| line.starts_with("break"))
+        .collect();
+    assert_eq!(breaks, ["break at sq", "break at sq"]);
+
+    assert!(f.clear_breakpoint("sq"));
+    assert!(!f.clear_breakpoint("sq"));
+}
+
+#[test]
+fn single_stepping_continues_until_resumed() {
+    let log = Log {
+        resume: vec![Resume::Continue, Resume::Step, Resume::Step, Resume::Step],
+        ..Log::default()
+    };
+    let mut f = Forth::new();
+    assert!(f.eval(": sq dup * ;").is_ok());
+    f.set_tracer(log.clone());
+    f.set_single_step(true);
+    assert!(f.eval("3 sq 1 +").is_ok());
+    assert_eq!(f.stack(), [10]);
+    let breaks: Vec<_> = log
+        .lines()
+        .into_iter()
+        .filter(|line| line.starts_with("break"))
+        .collect();
+    assert_eq!(
+        breaks,
+        ["break at 3", "break at sq", "break at dup", "break at *"]
+    );
+}
+
+#[test]
+fn abort_stops_execution_and_unwinds() {
+    let log = Log {
+        resume: vec![Resume::Abort],
+        ..Log::default()
+    };
+    let mut f = Forth::new();
+    assert!(f.eval(": boom 1 2 3 ; : outer 0 boom ;").is_ok());
+    f.set_tracer(log);
+    f.set_breakpoint("boom");
+    assert_eq!(f.eval("outer"), Err(Error::Aborted));
+    assert_eq!(f.stack(), [0]);
+
+    f.clear_tracer();
+    assert!(f.eval("outer").is_ok());
+    assert_eq!(f.stack(), [0, 0, 1, 2, 3]);
+}
@@ -0,0 +1,81 @@
+use forth::*;
+
+#[test]
+fn double_literals_take_two_cells() {
+    let mut f = Forth::new();
+    assert!(f.eval("123. -1. 4294967296.").is_ok());
+    assert_eq!(f.stack(), [123, 0, -1, -1, 0, 1]);
+}
+
+#[test]
+fn d_dot_prints_a_double() {
+    let mut f = Forth::new();
+    assert!(f.eval("10000000000. d. -5. d.").is_ok());
+    assert_eq!(f.output(), "10000000000 -5 ");
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn double_arithmetic_goes_beyond_a_cell() {
+    let mut f = Forth::new();
+    assert!(f.eval("2147483647. 1. d+ d.").is_ok());
+    assert!(f.eval("0. 3000000000. d- d.").is_ok());
+    assert_eq!(f.output(), "2147483648 -3000000000 ");
+}
+
+#[test]
+fn double_overflow_is_an_error() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("9223372036854775807. 1. d+"), Err(Error::Overflow));
+    assert_eq!(f.stack(), [-1, i32::MAX, 1, 0]);
+}
+
+#[test]
+fn mixed_multiplication() {
+    let mut f = Forth::new();
+    assert!(f.eval("100000 100000 m* d. -3 4 m* d.").is_ok());
+    assert!(f.eval("-1 2 um* d.").is_ok());
+    assert_eq!(f.output(), "10000000000 -12 8589934590 ");
+}
+
+#[test]
+fn um_slash_mod_divides_a_double() {
+    let mut f = Forth::new();
+    assert!(f.eval("10000000001. 100000 um/mod").is_ok());
+    assert_eq!(f.stack(), [1, 100000]);
+    assert_eq!(f.eval("1. 0 um/mod"), Err(Error::DivisionByZero));
+    assert!(f.eval("drop drop drop").is_ok());
+    assert_eq!(f.eval("10000000000. 1 um/mod"), Err(Error::Overflow));
+}
+
+#[test]
+fn converting_between_single_and_double() {
+    let mut f = Forth::new();
+    assert!(f.eval("-7 s>d 42 s>d d>s").is_ok());
+    assert_eq!(f.stack(), [-7, -1, 42]);
+    assert_eq!(f.eval("4294967296. d>s"), Err(Error::Overflow));
+}
+
+#[test]
+fn double_comparisons() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval("5000000000. 5000000000. d= 1. 2. d= -1. 1. d< 1. -1. d>")
+            .is_ok()
+    );
+    assert!(
+        f.eval("0. d0= 4294967296. d0= -4294967296. d0< 1. d0<")
+            .is_ok()
+    );
+    assert_eq!(f.stack(), [-1, 0, -1, -1, -1, 0, -1, 0]);
+}
+
+#[test]
+fn doubles_in_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": big ( -- lo hi ) 3000000000. 1. d+ ;").is_ok());
+    assert_eq!(f.stack_effect("big"), Some(StackEffect::new(0, 2)));
+    assert!(f.eval("see big big d.").is_ok());
+    assert_eq!(f.output(), ": big 3000000000. 1. d+ ;\n3000000001 ");
+    assert_eq!(f.eval(": 7. 1 ;"), Err(Error::InvalidWord));
+}
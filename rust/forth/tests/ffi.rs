@@ -0,0 +1,144 @@
+use std::ffi::{CStr, c_char};
+use std::path::Path;
+use std::process::Command;
+use std::ptr;
+use std::slice;
+
+use forth::ffi::*;
+use forth::*;
+
+fn stack(handle: *const ForthHandle) -> Vec<Value> {
+    unsafe {
+        let data = forth_stack(handle);
+        if data.is_null() {
+            return Vec::new();
+        }
+        slice::from_raw_parts(data, forth_stack_len(handle)).to_vec()
+    }
+}
+
+fn message(handle: *const ForthHandle) -> Option<String> {
+    let message = unsafe { forth_error_message(handle) };
+    (!message.is_null()).then(|| {
+        unsafe { CStr::from_ptr(message) }
+            .to_str()
+            .unwrap()
+            .to_string()
+    })
+}
+
+#[test]
+fn evaluates_and_reads_the_stack() {
+    let f = forth_new();
+    unsafe {
+        assert_eq!(forth_eval(f, c": sq dup * ;".as_ptr()), FORTH_OK);
+        assert_eq!(forth_eval(f, c"1 2 3 sq".as_ptr()), FORTH_OK);
+    }
+    assert_eq!(stack(f), [1, 2, 9]);
+    assert_eq!(message(f), None);
+    unsafe { forth_free(f) };
+}
+
+#[test]
+fn an_empty_stack_has_no_data() {
+    let f = forth_new();
+    unsafe {
+        assert_eq!(forth_stack_len(f), 0);
+        assert!(forth_stack(f).is_null());
+        forth_free(f);
+    }
+}
+
+#[test]
+fn errors_have_codes_and_messages() {
+    let f = forth_new();
+    unsafe {
+        assert_eq!(forth_eval(f, c"1 0 /".as_ptr()), FORTH_DIVISION_BY_ZERO);
+        assert_eq!(message(f).as_deref(), Some("division by zero"));
+        assert_eq!(forth_eval(f, c"foo".as_ptr()), FORTH_UNKNOWN_WORD);
+        assert_eq!(message(f).as_deref(), Some("unknown word"));
+        assert_eq!(forth_eval(f, c"drop".as_ptr()), FORTH_OK);
+        assert_eq!(message(f), None);
+        forth_free(f);
+    }
+}
+
+#[test]
+fn bad_arguments_are_rejected() {
+    let f = forth_new();
+    let invalid = [0x31u8, 0xff, 0];
+    unsafe {
+        assert_eq!(forth_eval(f, ptr::null()), FORTH_INVALID_ARGUMENT);
+        assert_eq!(
+            forth_eval(f, invalid.as_ptr().cast::<c_char>()),
+            FORTH_INVALID_ARGUMENT
+        );
+        assert_eq!(message(f).as_deref(), Some("source is not UTF-8"));
+        assert_eq!(
+            forth_eval(ptr::null_mut(), c"1".as_ptr()),
+            FORTH_INVALID_ARGUMENT
+        );
+        assert!(forth_error_message(ptr::null()).is_null());
+        forth_free(ptr::null_mut());
+        forth_free(f);
+    }
+}
+
+#[test]
+fn included_errors_report_the_code_of_their_cause() {
+    let error = Error::Included {
+        file: "lib.fs".to_string(),
+        line: 3,
+        error: Box::new(Error::StackUnderflow),
+    };
+    assert_eq!(error.code(), FORTH_STACK_UNDERFLOW);
+    assert_eq!(error.to_string(), "lib.fs:3: stack underflow");
+}
+
+#[test]
+fn the_header_declares_every_export() {
+    let header = include_str!("../include/forth.h");
+    let exports: Vec<&str> = include_str!("../src/ffi.rs")
+        .lines()
+        .filter_map(|line| line.split("extern \"C\" fn ").nth(1))
+        .map(|rest| rest.split('(').next().unwrap())
+        .collect();
+    assert_eq!(exports.len(), 6);
+    for export in exports {
+        assert!(header.contains(&format!("{export}(")), "{export}");
+    }
+}
+
+// Build `tests/c/embed.c` against the header and the shared library built
+// next to this test, and run it.
+#[cfg(unix)]
+#[test]
+fn a_c_program_builds_and_runs_against_the_header() {
+    let manifest = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let exe = std::env::current_exe().unwrap();
+    let libs = exe.parent().unwrap();
+    let program = Path::new(env!("CARGO_TARGET_TMPDIR")).join("embed");
+    let build = Command::new("cc")
+        .args(["-std=c99", "-Wall", "-Wextra", "-Werror", "-I"])
+        .arg(manifest.join("include"))
+        .arg(manifest.join("tests/c/embed.c"))
+        .arg("-L")
+        .arg(libs)
+        .arg(format!("-Wl,-rpath,{}", libs.display()))
+        .args(["-lforth", "-o"])
+        .arg(&program)
+        .output();
+    let Ok(build) = build else {
+        eprintln!("skipped: no C compiler");
+        return;
+    };
+    assert!(
+        build.status.success(),
+        "{}",
+        String::from_utf8_lossy(&build.stderr)
+    );
+
+    let run = Command::new(&program).output().unwrap();
+    assert!(run.status.success(), "exit status {}", run.status);
+    assert_eq!(run.stdout, b"ok\n");
+}
@@ -0,0 +1,75 @@
+use forth::*;
+
+#[test]
+fn short_definitions_stay_on_one_line() {
+    assert_eq!(
+        format(":   SQ ( n -- n )\n  DUP   * ;"),
+        ": SQ ( n -- n ) dup * ;\n"
+    );
+}
+
+#[test]
+fn branches_are_indented() {
+    let source = ": abs ( n -- n ) dup 0 < if -1 * else 0 + then 1 + ;";
+    assert_eq!(
+        format(source),
+        ": abs ( n -- n )\n  dup 0 < if\n    -1 *\n  else\n    0 +\n  then 1 + ;\n"
+    );
+}
+
+#[test]
+fn nested_branches_are_indented_further() {
+    let source = ": pick if if 1 else 2 then else 3 then ;";
+    assert_eq!(
+        format(source),
+        ": pick\n  if\n    if\n      1\n    else\n      2\n    then\n  else\n    3\n  then ;\n"
+    );
+}
+
+#[test]
+fn words_are_spelled_consistently() {
+    let source = ": Greet S\" Hi There\" TYPE ;\ngreet GREET\nSEE greet";
+    assert_eq!(
+        format(source),
+        ": Greet s\" Hi There\" type ;\nGreet Greet\nsee Greet\n"
+    );
+}
+
+#[test]
+fn file_names_keep_their_case() {
+    assert_eq!(format("INCLUDE Lib.fs"), "include Lib.fs\n");
+}
+
+#[test]
+fn top_level_lines_are_kept() {
+    let source = "1 2   +\n\n\n\n3 : one 1 ; one\n  4";
+    assert_eq!(format(source), "1 2 +\n\n3\n: one 1 ;\none\n4\n");
+}
+
+#[test]
+fn comments_and_locals_are_kept() {
+    let source = ": f {: a b :} ( add ) a b + ;";
+    assert_eq!(format(source), ": f {: a b :} ( add ) a b + ;\n");
+}
+
+#[test]
+fn formatting_is_idempotent_and_keeps_meaning() {
+    let source = "
+        : Fact ( n -- n! ) Recursive dup 1 > IF dup 1 - fact * ELSE drop 1 THEN ;
+        5 FACT   3 fact
+    ";
+    let formatted = format(source);
+    assert_eq!(format(&formatted), formatted);
+
+    let mut original = Forth::new();
+    let mut reformatted = Forth::new();
+    assert!(original.eval(source).is_ok());
+    assert!(reformatted.eval(&formatted).is_ok());
+    assert_eq!(original.stack(), reformatted.stack());
+    assert_eq!(reformatted.stack(), [120, 6]);
+}
+
+#[test]
+fn unfinished_definitions_are_left_open() {
+    assert_eq!(format(": half 2 /"), ": half 2 /\n");
+}
@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+use std::thread;
+
+use forth::*;
+
+fn prelude() -> Forth {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": price ( qty unit -- total ) * ; : greeting s\" hello\" ;")
+            .is_ok()
+    );
+    f
+}
+
+#[test]
+fn frozen_dictionaries_and_programs_cross_threads() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<Frozen>();
+    assert_send_sync::<Program>();
+}
+
+#[test]
+fn interpreters_start_from_a_frozen_dictionary() {
+    let frozen = prelude().freeze();
+    let mut f = Forth::from_frozen(&frozen);
+    assert!(f.stack().is_empty());
+    assert!(f.eval("3 4 price greeting type").is_ok());
+    assert_eq!(f.stack(), [12]);
+    assert_eq!(f.output(), "hello");
+}
+
+#[test]
+fn threads_share_one_prelude() {
+    let frozen = prelude().freeze();
+    let totals: Vec<_> = thread::scope(|scope| {
+        let handles: Vec<_> = (1..=8)
+            .map(|qty| {
+                let frozen = &frozen;
+                scope.spawn(move || {
+                    let mut f = Forth::from_frozen(frozen);
+                    f.call("price", &[qty, 10]).unwrap()
+                })
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(totals, (1..=8).map(|qty| [qty * 10]).collect::<Vec<_>>());
+}
+
+#[test]
+fn changes_stay_in_their_interpreter() {
+    let mut original = prelude();
+    let frozen = original.freeze();
+    let mut first = Forth::from_frozen(&frozen);
+    let mut second = Forth::from_frozen(&frozen);
+
+    assert!(first.eval(": price + ; : extra 1 ;").is_ok());
+    assert!(original.eval("forget price").is_ok());
+    assert!(second.eval("2 3 price").is_ok());
+    assert_eq!(second.stack(), [6]);
+    assert_eq!(second.eval("extra"), Err(Error::UnknownWord));
+    assert!(first.eval("2 3 price extra").is_ok());
+    assert_eq!(first.stack(), [5, 1]);
+    assert!(Forth::from_frozen(&frozen).eval("1 1 price").is_ok());
+}
+
+#[test]
+fn data_space_is_copied_on_write() {
+    let frozen = prelude().freeze();
+    let mut first = Forth::from_frozen(&frozen);
+    assert!(first.eval("greeting drop 72 swap c! greeting type").is_ok());
+    let mut second = Forth::from_frozen(&frozen);
+    assert!(second.eval("greeting type").is_ok());
+    assert_eq!(first.output(), "Hello");
+    assert_eq!(second.output(), "hello");
+}
+
+#[test]
+fn required_files_stay_loaded() {
+    let files = HashMap::from([("lib.fs".to_string(), "1".to_string())]);
+    let mut f = Forth::new();
+    f.set_loader(files.clone());
+    assert!(f.eval("require lib.fs").is_ok());
+
+    let mut copy = Forth::from_frozen(&f.freeze());
+    copy.set_loader(files);
+    assert!(copy.eval("require lib.fs").is_ok());
+    assert!(copy.stack().is_empty());
+}
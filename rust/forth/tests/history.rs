@@ -0,0 +1,153 @@
+use forth::*;
+
+fn recording(limit: usize) -> Forth {
+    let mut f = Forth::new();
+    f.record_history(limit);
+    f
+}
+
+fn instructions(f: &Forth) -> Vec<String> {
+    let history = f.history().unwrap();
+    history
+        .iter()
+        .map(|(_, step)| step.instruction().to_string())
+        .collect()
+}
+
+#[test]
+fn history_is_off_by_default() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 2 +").is_ok());
+    assert!(f.history().is_none());
+    assert_eq!(f.step_back(), None);
+    assert!(f.replay().is_ok());
+    assert_eq!(f.stack(), [3]);
+}
+
+#[test]
+fn each_step_records_the_stack_before_it() {
+    let mut f = recording(100);
+    assert!(f.eval(": sq dup * ; 3 sq").is_ok());
+    let history = f.history().unwrap();
+    assert_eq!(history.len(), 4);
+    let steps: Vec<_> = history
+        .iter()
+        .map(|(index, step)| (index, step.word(), step.stack().to_vec()))
+        .collect();
+    assert_eq!(
+        steps,
+        [
+            (0, None, vec![]),
+            (1, None, vec![3]),
+            (2, Some("sq"), vec![3]),
+            (3, Some("sq"), vec![3, 3]),
+        ]
+    );
+    assert_eq!(instructions(&f), ["3", "sq", "dup", "*"]);
+}
+
+#[test]
+fn stepping_back_restores_earlier_stacks() {
+    let mut f = recording(100);
+    assert!(f.eval("1 2 + 4 *").is_ok());
+    assert_eq!(f.stack(), [12]);
+    assert_eq!(f.step_back(), Some(4));
+    assert_eq!(f.stack(), [3, 4]);
+    assert_eq!(f.step_back(), Some(3));
+    assert_eq!(f.stack(), [3]);
+    assert!(f.rewind(1));
+    assert_eq!(f.stack(), [1]);
+    assert_eq!(f.step_back(), Some(0));
+    assert_eq!(f.stack(), []);
+    assert_eq!(f.step_back(), None);
+    assert_eq!(f.history().unwrap().position(), 0);
+}
+
+#[test]
+fn rewinding_can_move_forward_again() {
+    let mut f = recording(100);
+    assert!(f.eval("1 2 3").is_ok());
+    assert!(f.rewind(0));
+    assert!(f.rewind(2));
+    assert_eq!(f.stack(), [1, 2]);
+    assert!(!f.rewind(3));
+    assert_eq!(f.history().unwrap().len(), 3);
+}
+
+#[test]
+fn history_keeps_only_the_newest_steps() {
+    let mut f = recording(3);
+    assert!(f.eval("1 2 3 4 5").is_ok());
+    let history = f.history().unwrap();
+    assert_eq!(history.len(), 3);
+    assert_eq!((history.first(), history.end()), (2, 5));
+    assert!(history.get(1).is_none());
+    assert_eq!(history.get(2).unwrap().stack(), [1, 2]);
+    assert!(!f.rewind(1));
+
+    f.record_history(1);
+    assert_eq!(instructions(&f), ["5"]);
+    f.record_history(0);
+    assert!(f.history().is_none());
+}
+
+#[test]
+fn replay_runs_the_rest_of_the_word_again() {
+    let mut f = recording(100);
+    assert!(f.eval(": inc 1 + ; : twice inc inc ; 5 twice").is_ok());
+    assert_eq!(f.stack(), [7]);
+    assert!(f.rewind(3));
+    assert_eq!(f.history().unwrap().get(3).unwrap().word(), Some("inc"));
+    assert_eq!(f.stack(), [5]);
+    assert!(f.replay().is_ok());
+    assert_eq!(f.stack(), [7]);
+    assert_eq!(f.history().unwrap().end(), 8);
+}
+
+#[test]
+fn replay_restores_locals() {
+    let mut f = recording(100);
+    assert!(f.eval(": diff {: a b :} a b - ; 10 3 diff").is_ok());
+    assert_eq!(f.stack(), [7]);
+    let (local, _) = f
+        .history()
+        .unwrap()
+        .iter()
+        .find(|(_, step)| step.instruction().to_string() == "b")
+        .unwrap();
+    assert!(f.rewind(local));
+    assert_eq!(f.stack(), [10]);
+    assert!(f.replay().is_ok());
+    assert_eq!(f.stack(), [7]);
+}
+
+#[test]
+fn running_after_stepping_back_discards_later_steps() {
+    let mut f = recording(100);
+    assert!(f.eval("1 2 +").is_ok());
+    assert_eq!(f.step_back(), Some(2));
+    assert!(f.eval("*").is_ok());
+    assert_eq!(f.stack(), [2]);
+    assert_eq!(instructions(&f), ["1", "2", "*"]);
+    assert_eq!(f.history().unwrap().position(), 3);
+}
+
+#[test]
+fn failed_steps_are_recorded() {
+    let mut f = recording(100);
+    assert_eq!(f.eval("1 0 /"), Err(Error::DivisionByZero));
+    assert_eq!(f.step_back(), Some(2));
+    assert_eq!(f.stack(), [1, 0]);
+    assert_eq!(f.replay(), Err(Error::DivisionByZero));
+}
+
+#[test]
+fn the_closure_backend_is_recorded_too() {
+    let mut f = Forth::with_options(Options {
+        backend: Backend::Closures,
+        ..Options::default()
+    });
+    f.record_history(10);
+    assert!(f.eval(": sq dup * ; 4 sq").is_ok());
+    assert_eq!(instructions(&f), ["4", "sq", "dup", "*"]);
+}
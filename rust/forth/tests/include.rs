@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+use std::fs;
+
+use forth::*;
+
+fn with_files(files: &[(&str, &str)]) -> Forth {
+    let files: HashMap<String, String> = files
+        .iter()
+        .map(|(name, source)| (name.to_string(), source.to_string()))
+        .collect();
+    let mut f = Forth::new();
+    f.set_loader(files);
+    f
+}
+
+#[test]
+fn include_interprets_a_file() {
+    let mut f = with_files(&[("square.fs", ": square dup * ;\n3 square")]);
+    assert!(f.eval("include square.fs 2 square").is_ok());
+    assert_eq!(f.stack(), [9, 4]);
+}
+
+#[test]
+fn include_runs_the_file_every_time() {
+    let mut f = with_files(&[("one.fs", "1")]);
+    assert!(f.eval("include one.fs INCLUDE one.fs").is_ok());
+    assert_eq!(f.stack(), [1, 1]);
+}
+
+#[test]
+fn require_loads_a_file_once() {
+    let mut f = with_files(&[("one.fs", "1"), ("both.fs", "require one.fs 2")]);
+    assert!(f.eval("require one.fs require both.fs").is_ok());
+    assert!(f.eval("include both.fs").is_ok());
+    assert_eq!(f.stack(), [1, 2, 2]);
+}
+
+#[test]
+fn files_can_include_other_files() {
+    let mut f = with_files(&[
+        ("lib.fs", ": double 2 * ;"),
+        ("main.fs", "include lib.fs\n: quad double double ;"),
+    ]);
+    assert!(f.eval("include main.fs 5 quad").is_ok());
+    assert_eq!(f.stack(), [20]);
+}
+
+#[test]
+fn errors_report_the_file_and_line() {
+    let mut f = with_files(&[
+        ("bad.fs", "1 2\n\n3 nope 4"),
+        ("main.fs", ": ok ;\ninclude bad.fs"),
+    ]);
+    assert_eq!(
+        f.eval("include main.fs"),
+        Err(Error::Included {
+            file: "main.fs".to_string(),
+            line: 2,
+            error: Box::new(Error::Included {
+                file: "bad.fs".to_string(),
+                line: 3,
+                error: Box::new(Error::UnknownWord),
+            }),
+        })
+    );
+    assert_eq!(f.stack(), [1, 2, 3]);
+}
+
+#[test]
+fn root_finds_the_original_error() {
+    let mut f = with_files(&[("div.fs", "1 0 /")]);
+    let error = f.eval("include div.fs").unwrap_err();
+    assert_eq!(error.root(), &Error::DivisionByZero);
+    assert_eq!(Error::StackUnderflow.root(), &Error::StackUnderflow);
+}
+
+#[test]
+fn missing_files_are_reported_by_the_includer() {
+    let mut f = with_files(&[("main.fs", "1\ninclude gone.fs")]);
+    assert_eq!(f.eval("include gone.fs"), Err(Error::UnknownFile));
+    assert_eq!(
+        f.eval("include main.fs"),
+        Err(Error::Included {
+            file: "main.fs".to_string(),
+            line: 2,
+            error: Box::new(Error::UnknownFile),
+        })
+    );
+    assert_eq!(f.eval("include"), Err(Error::InvalidWord));
+}
+
+#[test]
+fn require_skips_a_file_that_is_being_loaded() {
+    let mut f = with_files(&[("a.fs", "1 require b.fs"), ("b.fs", "require a.fs 2")]);
+    assert!(f.eval("require a.fs").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn require_loads_a_file_again_after_it_failed() {
+    let mut f = with_files(&[("half.fs", ": half 2 / ; 1 0 /")]);
+    assert!(f.eval("require half.fs").is_err());
+    assert!(f.eval("require half.fs").is_err());
+    assert_eq!(f.stack(), [1, 0, 1, 0]);
+}
+
+#[test]
+fn a_file_cannot_include_itself() {
+    let mut f = with_files(&[("a.fs", "include b.fs"), ("b.fs", "include a.fs")]);
+    let error = f.eval("include a.fs").unwrap_err();
+    assert_eq!(error.root(), &Error::InvalidWord);
+}
+
+#[test]
+fn compiled_programs_include_the_file_code() {
+    let mut f = with_files(&[("body.fs", ": seven 7 ; seven")]);
+    let program = f.compile("include body.fs 1 +").unwrap();
+    assert!(f.stack().is_empty());
+    assert!(f.run(&program).is_ok());
+    assert!(f.run(&program).is_ok());
+    assert_eq!(f.stack(), [8, 8]);
+}
+
+#[test]
+fn file_loader_reads_from_a_directory() {
+    let root = std::env::temp_dir().join(format!("forth-include-{}", std::process::id()));
+    fs::create_dir_all(&root).unwrap();
+    fs::write(root.join("cube.fs"), ": cube dup dup * * ;\n").unwrap();
+
+    let mut f = Forth::new();
+    f.set_loader(FileLoader::new(&root));
+    assert!(f.eval("require cube.fs 3 cube").is_ok());
+    assert_eq!(f.stack(), [27]);
+    assert_eq!(f.eval("include missing.fs"), Err(Error::UnknownFile));
+
+    fs::remove_dir_all(&root).unwrap();
+}
+
+#[test]
+fn file_loader_stays_inside_its_root() {
+    let base = std::env::temp_dir().join(format!("forth-root-{}", std::process::id()));
+    let root = base.join("root");
+    fs::create_dir_all(&root).unwrap();
+    fs::write(base.join("secret.fs"), "1").unwrap();
+    fs::write(root.join("open.fs"), "2").unwrap();
+
+    let mut f = Forth::new();
+    f.set_loader(FileLoader::new(&root));
+    let secret = base.join("secret.fs");
+    for name in ["../secret.fs", "./../secret.fs", secret.to_str().unwrap()] {
+        let source = format!("include {name}");
+        assert_eq!(f.eval(&source), Err(Error::UnknownFile), "{name}");
+    }
+    assert!(f.eval("include ./open.fs").is_ok());
+    assert_eq!(f.stack(), [2]);
+
+    fs::remove_dir_all(&base).unwrap();
+}
@@ -0,0 +1,71 @@
+use forth::*;
+
+#[test]
+fn words_lists_definitions_newest_first() {
+    let mut f = Forth::new();
+    assert!(f.eval(": foo 1 ; : bar 2 ; : baz 3 ;").is_ok());
+    assert_eq!(f.words().collect::<Vec<_>>(), ["baz", "bar", "foo"]);
+    assert!(f.eval("WORDS").is_ok());
+    assert_eq!(f.output(), "baz bar foo\n");
+}
+
+#[test]
+fn redefined_words_are_listed_once() {
+    let mut f = Forth::new();
+    assert!(f.eval(": foo 1 ; : bar 2 ; : FOO 3 ;").is_ok());
+    assert_eq!(f.words().collect::<Vec<_>>(), ["foo", "bar"]);
+}
+
+#[test]
+fn definition_returns_the_compiled_body() {
+    let mut f = Forth::new();
+    assert!(f.eval(": double 2 * ; : quad double DUP + ;").is_ok());
+    assert_eq!(
+        f.definition("QUAD"),
+        Some(Definition {
+            name: "quad",
+            body: vec![
+                Instruction::Call("double"),
+                Instruction::Builtin("dup"),
+                Instruction::Builtin("+"),
+            ],
+        })
+    );
+    assert_eq!(f.definition("dup"), None);
+    assert_eq!(f.definition("missing"), None);
+}
+
+#[test]
+fn see_decompiles_a_definition() {
+    let mut f = Forth::new();
+    assert!(f.eval(": five 2 3 + ; : ten five DUP + ;").is_ok());
+    assert!(f.eval("see ten SEE five").is_ok());
+    assert_eq!(f.take_output(), ": ten five dup + ;\n: five 2 3 + ;\n");
+    assert_eq!(f.output(), "");
+}
+
+#[test]
+fn see_shows_the_definition_a_word_captured() {
+    let mut f = Forth::new();
+    assert!(f.eval(": foo 5 ; : bar foo ; : foo 6 ;").is_ok());
+    assert_eq!(
+        f.definition("bar").unwrap().body,
+        [Instruction::Call("foo")]
+    );
+    assert!(f.eval("see foo").is_ok());
+    assert_eq!(f.output(), ": foo 6 ;\n");
+}
+
+#[test]
+fn see_reports_built_ins() {
+    let mut f = Forth::new();
+    assert!(f.eval("see DUP").is_ok());
+    assert_eq!(f.output(), "dup is built-in\n");
+}
+
+#[test]
+fn see_errors_on_unknown_or_missing_names() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("see nothing"), Err(Error::UnknownWord));
+    assert_eq!(f.eval("see"), Err(Error::InvalidWord));
+}
@@ -0,0 +1,123 @@
+use forth::*;
+
+fn kinds(source: &str) -> Vec<(usize, String, LintKind)> {
+    lint(source)
+        .into_iter()
+        .map(|lint| (lint.line, lint.name, lint.kind))
+        .collect()
+}
+
+#[test]
+fn clean_source_has_no_lints() {
+    let source = ": sq ( n -- n ) dup * ;\n: quad ( n -- n ) sq sq ;\n3 quad";
+    assert_eq!(lint(source), []);
+}
+
+#[test]
+fn redefined_builtins_are_reported() {
+    let source = ": DUP ( a -- a a ) {: a :} a a ;\nvocabulary words\n1 dup";
+    assert_eq!(
+        kinds(source),
+        [
+            (1, "DUP".to_string(), LintKind::RedefinedBuiltin),
+            (2, "words".to_string(), LintKind::RedefinedBuiltin),
+        ]
+    );
+}
+
+#[test]
+fn unused_definitions_are_reported() {
+    let source = ": helper 1 ;\n: main helper ;\n: spare 2 ;\nmain";
+    assert_eq!(
+        kinds(source),
+        [(3, "spare".to_string(), LintKind::UnusedDefinition)]
+    );
+}
+
+#[test]
+fn definitions_replaced_before_use_are_unused() {
+    let source = ": x 1 ;\n: x 2 ;\n: y x ;\n: x y ;\nx";
+    assert_eq!(
+        kinds(source),
+        [(1, "x".to_string(), LintKind::UnusedDefinition)]
+    );
+}
+
+#[test]
+fn see_and_forget_count_as_uses() {
+    assert_eq!(lint(": a 1 ;\n: b 2 ;\nsee a forget B"), []);
+}
+
+#[test]
+fn recursion_alone_is_not_a_use() {
+    let source = ": down recursive dup if 1 - down then ;";
+    assert_eq!(
+        kinds(source),
+        [(1, "down".to_string(), LintKind::UnusedDefinition)]
+    );
+}
+
+#[test]
+fn definitions_that_always_underflow_are_reported() {
+    let source = "
+        : pair ( -- a b ) 1 ;
+        : bad ( a -- ) drop pair + + ;
+        : worse ( n -- ) if drop else + then ;
+        : fine ( n -- ) if drop then ;
+        : unknown ( -- ) mystery + ;
+        bad worse fine unknown
+    ";
+    assert_eq!(
+        kinds(source),
+        [
+            (3, "bad".to_string(), LintKind::AlwaysUnderflows),
+            (4, "worse".to_string(), LintKind::AlwaysUnderflows),
+        ]
+    );
+}
+
+#[test]
+fn inferred_effects_are_used_by_later_definitions() {
+    let source = ": two 1 1 ;\n: sum ( -- n ) two + + ;\nsum";
+    assert_eq!(
+        kinds(source),
+        [(2, "sum".to_string(), LintKind::AlwaysUnderflows)]
+    );
+}
+
+#[test]
+fn undeclared_definitions_are_given_their_locals() {
+    let source = ": sum3 {: a b :} a b + + ;\n: sum2 {: a b :} a b + ;\nsum3 sum2";
+    assert_eq!(
+        kinds(source),
+        [(1, "sum3".to_string(), LintKind::AlwaysUnderflows)]
+    );
+}
+
+#[test]
+fn effects_are_inferred_through_branches() {
+    let source = ": abs dup 0 < if -1 * then ;\n: bad ( -- n ) abs ;\nbad";
+    assert_eq!(
+        kinds(source),
+        [(2, "bad".to_string(), LintKind::AlwaysUnderflows)]
+    );
+}
+
+#[test]
+fn locals_are_not_words() {
+    let source = ": f ( a b -- c ) {: a b | c :} a b + to c c ;\n: a 1 ;\nf a";
+    assert_eq!(lint(source), []);
+}
+
+#[test]
+fn lints_are_displayed_with_their_line() {
+    let lints = lint("\n: dup over ;");
+    let lines: Vec<String> = lints.iter().map(ToString::to_string).collect();
+    assert_eq!(
+        lines,
+        [
+            "2: `dup` redefines a built-in word",
+            "2: `dup` is never used"
+        ]
+    );
+}
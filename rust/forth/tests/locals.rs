@@ -0,0 +1,108 @@
+use forth::*;
+
+#[test]
+fn locals_are_taken_from_the_stack_in_order() {
+    let mut f = Forth::new();
+    assert!(f.eval(": minus {: a b -- c :} a b - ;").is_ok());
+    assert!(f.eval("10 3 minus").is_ok());
+    assert_eq!(f.stack(), [7]);
+}
+
+#[test]
+fn locals_replace_stack_juggling() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": hyp2 ( a b -- c ) {: a b :} a a * b b * + ;")
+            .is_ok()
+    );
+    assert!(f.eval("1 3 4 hyp2").is_ok());
+    assert_eq!(f.stack(), [1, 25]);
+    assert_eq!(f.stack_effect("hyp2"), Some(StackEffect::new(2, 1)));
+}
+
+#[test]
+fn to_assigns_a_local() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": bump {: n | total :} n 2 * to total total 1 + ;")
+            .is_ok()
+    );
+    assert!(f.eval("20 bump").is_ok());
+    assert_eq!(f.stack(), [41]);
+}
+
+#[test]
+fn uninitialized_locals_start_at_zero() {
+    let mut f = Forth::new();
+    assert!(f.eval(": zero {: | x :} x ;").is_ok());
+    assert!(f.eval("zero").is_ok());
+    assert_eq!(f.stack(), [0]);
+}
+
+#[test]
+fn locals_are_case_insensitive_and_shadow_words() {
+    let mut f = Forth::new();
+    assert!(f.eval(": dup 99 ; : pick {: DUP :} dup Dup + ;").is_ok());
+    assert!(f.eval("4 pick").is_ok());
+    assert_eq!(f.stack(), [8]);
+}
+
+#[test]
+fn locals_are_invisible_outside_the_defining_word() {
+    let mut f = Forth::new();
+    assert!(f.eval(": first {: secret :} secret ;").is_ok());
+    assert_eq!(f.eval(": second secret ;"), Err(Error::UnknownWord));
+    assert_eq!(f.eval("secret"), Err(Error::UnknownWord));
+    assert_eq!(f.eval(": third to secret ;"), Err(Error::InvalidWord));
+}
+
+#[test]
+fn each_call_has_its_own_locals() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": fact {: n :} n 1 > if n 1 - recurse n * else 1 then ;")
+            .is_ok()
+    );
+    assert!(f.eval(": outer {: x :} x fact x ;").is_ok());
+    assert!(f.eval("5 outer").is_ok());
+    assert_eq!(f.stack(), [120, 5]);
+}
+
+#[test]
+fn tail_recursion_with_locals_runs_in_constant_space() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": count {: acc n :} n if acc 1 + n 1 - recurse else acc then ;")
+            .is_ok()
+    );
+    assert!(f.eval("0 200000 count").is_ok());
+    assert_eq!(f.stack(), [200000]);
+}
+
+#[test]
+fn missing_arguments_underflow() {
+    let mut f = Forth::new();
+    assert!(f.eval(": pair {: a b :} a b ;").is_ok());
+    assert_eq!(f.eval("1 pair"), Err(Error::StackUnderflow));
+    assert_eq!(f.stack(), [1]);
+}
+
+#[test]
+fn declarations_must_come_first_and_be_closed() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval(": late 1 {: a :} a ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": open {: a b ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": twice {: a :} {: b :} ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("{: a :}"), Err(Error::InvalidWord));
+}
+
+#[test]
+fn see_shows_the_declaration() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": bump {: n | total -- x :} n to total total ;")
+            .is_ok()
+    );
+    assert!(f.eval("see bump").is_ok());
+    assert_eq!(f.output(), ": bump {: n | total :} n to total total ;\n");
+}
@@ -0,0 +1,46 @@
+//! Looking up words must not allocate: tokens are case-folded into a reused
+//! buffer and resolved through interned symbols.
+
+use forth::Forth;
+
+#[test]
+fn repeated_eval_does_not_allocate() {
+    let mut f = Forth::new();
+    assert!(f.eval(": twice DUP + ;").is_ok());
+    let source = "1 DUP Dup dup TWICE twice drop drop drop";
+
+    // Let the stacks and buffers reach their working size first.
+    assert!(f.eval(source).is_ok());
+    let before = GLOBAL_ALLOCATOR.allocations();
+    assert!(f.eval(source).is_ok());
+    assert_eq!(GLOBAL_ALLOCATOR.allocations(), before);
+    assert_eq!(f.stack(), [1, 1]);
+}
+
+use std::alloc::{GlobalAlloc, Layout, System as SystemAllocator};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// This allocator wraps the default allocator, and counts how many
+/// allocations have been made.
+struct CountingAllocator<A: GlobalAlloc>(A, AtomicU64);
+
+unsafe impl<A: GlobalAlloc> GlobalAlloc for CountingAllocator<A> {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.1.fetch_add(1, Ordering::SeqCst);
+        unsafe { self.0.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { self.0.dealloc(ptr, layout) };
+    }
+}
+
+impl<A: GlobalAlloc> CountingAllocator<A> {
+    fn allocations(&self) -> u64 {
+        self.1.load(Ordering::SeqCst)
+    }
+}
+
+#[global_allocator]
+static GLOBAL_ALLOCATOR: CountingAllocator<SystemAllocator> =
+    CountingAllocator(SystemAllocator, AtomicU64::new(0));
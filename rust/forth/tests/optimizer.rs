@@ -0,0 +1,111 @@
+use forth::*;
+
+fn optimizing() -> Forth {
+    let mut f = Forth::new();
+    f.set_optimize(true);
+    f
+}
+
+fn body(f: &Forth, name: &str) -> Vec<String> {
+    let definition = f.definition(name).unwrap();
+    definition.body.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn constant_arithmetic_is_folded() {
+    let mut f = optimizing();
+    assert!(f.eval(": five 2 3 + ;").is_ok());
+    assert!(f.eval(": big 2 3 * 4 + 1 - dup * ;").is_ok());
+    assert_eq!(body(&f, "five"), ["5"]);
+    assert_eq!(body(&f, "big"), ["81"]);
+    assert!(f.eval("five big").is_ok());
+    assert_eq!(f.stack(), [5, 81]);
+}
+
+#[test]
+fn folding_is_off_by_default() {
+    let mut f = Forth::new();
+    assert!(f.eval(": five 2 3 + ;").is_ok());
+    assert_eq!(body(&f, "five"), ["2", "3", "+"]);
+}
+
+#[test]
+fn division_by_zero_is_left_for_run_time() {
+    let mut f = optimizing();
+    assert!(f.eval(": boom 1 0 / ;").is_ok());
+    assert_eq!(body(&f, "boom"), ["1", "0", "/"]);
+    assert_eq!(f.eval("boom"), Err(Error::DivisionByZero));
+    assert_eq!(f.stack(), [1, 0]);
+}
+
+#[test]
+fn redundant_shuffles_keep_their_underflow_check() {
+    let mut f = optimizing();
+    assert!(f.eval(": noop dup drop dup drop ;").is_ok());
+    assert_eq!(body(&f, "noop"), ["( need 1 )"]);
+    assert_eq!(f.eval("noop"), Err(Error::StackUnderflow));
+    assert!(f.eval("1 noop").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
+
+#[test]
+fn depth_checks_merge_into_following_ops() {
+    let mut f = optimizing();
+    assert!(f.eval(": add dup drop + ;").is_ok());
+    assert_eq!(body(&f, "add"), ["+"]);
+    assert!(f.eval(": add-one 1 dup drop + ;").is_ok());
+    assert_eq!(body(&f, "add-one"), ["1", "+"]);
+}
+
+#[test]
+fn small_words_are_inlined_and_folded() {
+    let mut f = optimizing();
+    assert!(f.eval(": sq dup * ; : nine 3 sq ; : quad sq sq ;").is_ok());
+    assert_eq!(body(&f, "nine"), ["9"]);
+    assert_eq!(body(&f, "quad"), ["dup", "*", "dup", "*"]);
+    assert!(f.eval("nine quad").is_ok());
+    assert_eq!(f.stack(), [6561]);
+}
+
+#[test]
+fn large_words_are_still_called() {
+    let mut f = optimizing();
+    assert!(
+        f.eval(": big dup dup * * dup + ; : bigger big 1 + ;")
+            .is_ok()
+    );
+    assert_eq!(body(&f, "bigger"), ["big", "1", "+"]);
+}
+
+#[test]
+fn shuffle_pairs_keep_their_partial_failure() {
+    let source = ": twice swap swap ; : peek over drop ;";
+    let mut fast = optimizing();
+    assert!(fast.eval(source).is_ok());
+    assert_eq!(body(&fast, "twice"), ["( need 2 or drop )"]);
+    assert_eq!(body(&fast, "peek"), ["( need 2 or drop )"]);
+    for word in ["twice", "peek"] {
+        for input in ["1", "", "1 2"] {
+            let mut plain = Forth::new();
+            let mut fast = optimizing();
+            assert!(plain.eval(source).is_ok());
+            assert!(fast.eval(source).is_ok());
+            let input = format!("{input} {word}");
+            assert_eq!(plain.eval(&input), fast.eval(&input), "{input}");
+            assert_eq!(plain.stack(), fast.stack(), "{input}");
+        }
+    }
+}
+
+#[test]
+fn optimized_and_plain_code_agree() {
+    let source = ": a 4 2 / swap - ; : b a a over * ; : c 7 b dup + ;";
+    for input in ["1 2 3 c", "1 c", "c", "0 0 c"] {
+        let mut plain = Forth::new();
+        let mut fast = optimizing();
+        assert!(plain.eval(source).is_ok());
+        assert!(fast.eval(source).is_ok());
+        assert_eq!(plain.eval(input), fast.eval(input), "{input}");
+        assert_eq!(plain.stack(), fast.stack(), "{input}");
+    }
+}
@@ -0,0 +1,99 @@
+use forth::*;
+
+fn with_case(case: CaseFolding) -> Forth {
+    Forth::with_options(Options {
+        case,
+        ..Options::default()
+    })
+}
+
+fn reserving() -> Forth {
+    Forth::with_options(Options {
+        reserve_builtins: true,
+        ..Options::default()
+    })
+}
+
+#[test]
+fn default_options_fold_unicode() {
+    let mut f = Forth::new();
+    assert_eq!(Options::default().case, CaseFolding::Unicode);
+    assert!(f.eval(": ÄRGER 1 ; ärger Ärger DUP").is_ok());
+    assert_eq!(f.stack(), [1, 1, 1]);
+}
+
+#[test]
+fn ascii_folding_leaves_other_letters_alone() {
+    let mut f = with_case(CaseFolding::Ascii);
+    assert!(f.eval(": ÄRGER 1 ; ÄrGeR Ärger").is_ok());
+    assert_eq!(f.stack(), [1, 1]);
+    assert_eq!(f.eval("ärger"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn case_sensitive_names_must_match_exactly() {
+    let mut f = with_case(CaseFolding::Sensitive);
+    assert!(f.eval(": Foo 1 ; : foo 2 ; Foo foo").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+    assert_eq!(f.eval("FOO"), Err(Error::UnknownWord));
+    assert_eq!(f.words().collect::<Vec<_>>(), ["foo", "Foo"]);
+}
+
+#[test]
+fn case_sensitive_builtins_are_lowercase() {
+    let mut f = with_case(CaseFolding::Sensitive);
+    assert!(f.eval("1 dup : two 2 ; two").is_ok());
+    assert_eq!(f.stack(), [1, 1, 2]);
+    assert_eq!(f.eval("DUP"), Err(Error::UnknownWord));
+    assert_eq!(f.eval(": x 1 IF 2 THEN ;"), Err(Error::UnknownWord));
+    assert_eq!(f.stack_effect("SWAP"), None);
+}
+
+#[test]
+fn case_sensitive_locals() {
+    let mut f = with_case(CaseFolding::Sensitive);
+    assert!(f.eval(": pick {: A a :} A a - ; 10 3 pick").is_ok());
+    assert_eq!(f.stack(), [7]);
+}
+
+#[test]
+fn builtins_can_be_redefined_by_default() {
+    let mut f = Forth::new();
+    assert!(f.eval(": + * ; 3 4 +").is_ok());
+    assert_eq!(f.stack(), [12]);
+}
+
+#[test]
+fn reserved_builtins_cannot_be_redefined() {
+    let mut f = reserving();
+    assert_eq!(f.eval(": + * ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": DUP 1 ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": see 1 ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": if 1 ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("vocabulary swap"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("marker words"), Err(Error::InvalidWord));
+    assert!(f.words().next().is_none());
+    assert!(f.eval("3 4 + : sum + ; 1 2 sum").is_ok());
+    assert_eq!(f.stack(), [7, 3]);
+}
+
+#[test]
+fn reserved_case_sensitive_only_reserves_the_exact_names() {
+    let mut f = Forth::with_options(Options {
+        case: CaseFolding::Sensitive,
+        reserve_builtins: true,
+        ..Options::default()
+    });
+    assert_eq!(f.eval(": dup 1 ;"), Err(Error::InvalidWord));
+    assert!(f.eval(": DUP 1 ; DUP").is_ok());
+    assert_eq!(f.stack(), [1]);
+}
+
+#[test]
+fn frozen_interpreters_keep_their_options() {
+    let mut f = with_case(CaseFolding::Sensitive);
+    assert!(f.eval(": Go 1 ;").is_ok());
+    let mut copy = Forth::from_frozen(&f.freeze());
+    assert!(copy.eval("Go").is_ok());
+    assert_eq!(copy.eval("go"), Err(Error::UnknownWord));
+}
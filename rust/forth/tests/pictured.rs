@@ -0,0 +1,86 @@
+use forth::*;
+
+#[test]
+fn dot_and_u_dot_print_numbers() {
+    let mut f = Forth::new();
+    assert!(f.eval("42 . -7 . -1 u. 5 u.").is_ok());
+    assert_eq!(f.output(), "42 -7 4294967295 5 ");
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn right_aligned_numbers() {
+    let mut f = Forth::new();
+    assert!(f.eval("42 5 .r -42 5 .r 123456 3 .r").is_ok());
+    assert!(f.eval("-1 12 u.r 7 -3 u.r").is_ok());
+    assert_eq!(f.output(), "   42  -42123456  42949672957");
+}
+
+#[test]
+fn sharp_s_converts_every_digit() {
+    let mut f = Forth::new();
+    assert!(f.eval("12345. <# #s #> type 0. <# #s #> type").is_ok());
+    assert_eq!(f.output(), "123450");
+}
+
+#[test]
+fn pictures_can_mix_digits_and_held_characters() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": money ( lo hi -- ) <# # # 46 hold #s 36 hold #> type ;")
+            .is_ok()
+    );
+    assert!(f.eval("123456. money 5. money").is_ok());
+    assert_eq!(f.output(), "$1234.56$0.05");
+}
+
+#[test]
+fn sign_holds_a_minus_for_negative_numbers() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval("17. <# #s -1 sign #> type 17. <# #s 1 sign #> type")
+            .is_ok()
+    );
+    assert_eq!(f.output(), "-1717");
+}
+
+#[test]
+fn hash_greater_leaves_a_string() {
+    let mut f = Forth::new();
+    assert!(f.eval("2024. <# # # 45 hold # # #>").is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("20-24"));
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn pictures_fit_in_the_hold_buffer() {
+    let mut f = Forth::new();
+    assert!(f.eval("<#").is_ok());
+    let holds = "48 hold ".repeat(128);
+    assert!(f.eval(&holds).is_ok());
+    assert_eq!(f.eval("48 hold"), Err(Error::DataSpaceOverflow));
+    assert_eq!(f.stack(), [48]);
+}
+
+#[test]
+fn field_widths_are_limited() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 200 .r").is_ok());
+    assert_eq!(f.output().len(), 200);
+    assert!(f.eval("1 4096 u.r").is_ok());
+    assert_eq!(f.output().len(), 200 + 4096);
+    assert_eq!(f.eval("1 4097 .r"), Err(Error::InvalidWidth));
+    assert_eq!(
+        f.eval("drop drop 1 2000000000 u.r"),
+        Err(Error::InvalidWidth)
+    );
+    assert_eq!(f.stack(), [1, 2000000000]);
+}
+
+#[test]
+fn number_output_words_have_stack_effects() {
+    let f = Forth::new();
+    assert_eq!(f.stack_effect("#s"), Some(StackEffect::new(2, 2)));
+    assert_eq!(f.stack_effect("#>"), Some(StackEffect::new(2, 2)));
+    assert_eq!(f.stack_effect("u.r"), Some(StackEffect::new(2, 0)));
+}
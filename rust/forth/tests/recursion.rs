@@ -0,0 +1,112 @@
+use forth::*;
+
+#[test]
+fn if_else_then_chooses_a_branch() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": sign dup 0 < if drop -1 else 0 > if 1 else 0 then then ;")
+            .is_ok()
+    );
+    assert!(f.eval("-5 sign 0 sign 7 sign").is_ok());
+    assert_eq!(f.stack(), [-1, 0, 1]);
+}
+
+#[test]
+fn comparisons_leave_forth_flags() {
+    let mut f = Forth::new();
+    assert!(f.eval("1 1 = 1 2 = 1 2 < 2 1 <").is_ok());
+    assert_eq!(f.stack(), [-1, 0, -1, 0]);
+}
+
+#[test]
+fn control_words_only_work_inside_definitions() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("1 if 2 then"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("recurse"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": broken 1 if 2 ;"), Err(Error::InvalidWord));
+    assert_eq!(f.eval(": broken 1 then ;"), Err(Error::InvalidWord));
+}
+
+#[test]
+fn recurse_calls_the_word_being_defined() {
+    let mut f = Forth::new();
+    assert!(f.eval(": fact dup 1 > if dup 1 - recurse * then ;").is_ok());
+    assert!(f.eval("5 fact 1 fact").is_ok());
+    assert_eq!(f.stack(), [120, 1]);
+}
+
+#[test]
+fn recursive_makes_the_name_visible_in_its_own_body() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": fib recursive dup 1 > if dup 1 - fib swap 2 - FIB + then ;")
+            .is_ok()
+    );
+    assert!(f.eval("10 fib").is_ok());
+    assert_eq!(f.stack(), [55]);
+}
+
+#[test]
+fn without_recursive_the_old_definition_is_called() {
+    let mut f = Forth::new();
+    assert!(f.eval(": foo 10 ; : foo foo 1 + ;").is_ok());
+    assert!(f.eval("foo").is_ok());
+    assert_eq!(f.stack(), [11]);
+}
+
+#[test]
+fn tail_recursion_runs_in_constant_space() {
+    let mut f = Forth::new();
+    assert!(f.eval(": countdown dup if 1 - recurse then ;").is_ok());
+    assert!(f.eval("1000000 countdown").is_ok());
+    assert_eq!(f.stack(), [0]);
+}
+
+#[test]
+fn tail_recursion_through_else_runs_in_constant_space() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": down recursive dup 0 = if else 1 - down then ;")
+            .is_ok()
+    );
+    assert!(f.eval("1000000 down").is_ok());
+    assert_eq!(f.stack(), [0]);
+}
+
+#[test]
+fn see_shows_recursion_as_it_was_written() {
+    let mut f = Forth::new();
+    assert!(f.eval(": down dup if 1 - recurse then ;").is_ok());
+    assert!(f.eval("see down").is_ok());
+    let source = f.take_output();
+    assert_eq!(source, ": down dup if 1 - recurse then ;\n");
+
+    let mut g = Forth::new();
+    assert!(g.eval(&source).is_ok());
+    assert!(g.eval("3 down").is_ok());
+    assert_eq!(g.stack(), [0]);
+}
+
+#[test]
+fn see_rebuilds_nested_conditionals() {
+    let mut f = Forth::new();
+    for source in [
+        ": sign dup 0 < if drop -1 else 0 > if 1 else 0 then then ;",
+        ": nested if if 1 else then else 2 then ;",
+        ": empty if then if else then ;",
+    ] {
+        assert!(f.eval(source).is_ok());
+        let name = source.split_whitespace().nth(1).unwrap();
+        assert!(f.eval(&format!("see {name}")).is_ok());
+        assert_eq!(f.take_output(), format!("{source}\n"));
+    }
+}
+
+#[test]
+fn unbounded_recursion_overflows_the_return_stack() {
+    let mut f = Forth::new();
+    assert!(f.eval(": forever recurse 1 ;").is_ok());
+    assert_eq!(f.eval("forever"), Err(Error::ReturnStackOverflow));
+    assert!(f.eval("2").is_ok());
+    assert_eq!(f.stack(), [2]);
+}
@@ -0,0 +1,92 @@
+use forth::*;
+
+#[test]
+fn built_ins_have_fixed_effects() {
+    let f = Forth::new();
+    assert_eq!(f.stack_effect("+"), Some(StackEffect::new(2, 1)));
+    assert_eq!(f.stack_effect("OVER"), Some(StackEffect::new(2, 3)));
+    assert_eq!(f.stack_effect("nothing"), None);
+}
+
+#[test]
+fn effects_are_inferred_for_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": five 2 3 + ;").is_ok());
+    assert!(f.eval(": sq dup * ;").is_ok());
+    assert!(f.eval(": sum-sq sq swap sq + ;").is_ok());
+    assert!(f.eval(": nothing ;").is_ok());
+    assert_eq!(f.stack_effect("five"), Some(StackEffect::new(0, 1)));
+    assert_eq!(f.stack_effect("sq"), Some(StackEffect::new(1, 1)));
+    assert_eq!(f.stack_effect("sum-sq"), Some(StackEffect::new(2, 1)));
+    assert_eq!(f.stack_effect("nothing"), Some(StackEffect::new(0, 0)));
+}
+
+#[test]
+fn composing_effects_accounts_for_deeper_inputs() {
+    let effect = StackEffect::new(0, 1).then(StackEffect::new(3, 2));
+    assert_eq!(effect, StackEffect::new(2, 2));
+    let effect = StackEffect::new(1, 3).then(StackEffect::new(2, 1));
+    assert_eq!(effect, StackEffect::new(1, 2));
+}
+
+#[test]
+fn declared_effect_is_checked_and_stored() {
+    let mut f = Forth::new();
+    assert!(f.eval(": sq ( n -- n*n ) dup * ;").is_ok());
+    assert_eq!(f.stack_effect("sq"), Some(StackEffect::new(1, 1)));
+    assert!(f.eval(": keep ( a b -- a b 1 ) 1 ;").is_ok());
+    assert_eq!(f.stack_effect("keep"), Some(StackEffect::new(2, 3)));
+}
+
+#[test]
+fn definitions_that_underflow_their_declaration_are_rejected() {
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval(": bad ( a -- b ) + ;"),
+        Err(Error::StackEffectMismatch)
+    );
+    assert_eq!(f.eval("bad"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn definitions_that_leave_the_wrong_depth_are_rejected() {
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval(": bad ( a -- b ) dup ;"),
+        Err(Error::StackEffectMismatch)
+    );
+}
+
+#[test]
+fn comments_without_a_separator_are_ignored() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": five ( just a comment ) 2 ( and another ) 3 + ;")
+            .is_ok()
+    );
+    assert!(f.eval("( top level ) five").is_ok());
+    assert_eq!(f.stack(), [5]);
+    assert_eq!(f.stack_effect("five"), Some(StackEffect::new(0, 1)));
+}
+
+#[test]
+fn effects_are_inferred_through_branches() {
+    let mut f = Forth::new();
+    assert!(f.eval(": abs dup 0 < if -1 * then ;").is_ok());
+    assert!(f.eval(": sign ( n -- n ) 0 < if -1 else 1 then ;").is_ok());
+    assert_eq!(f.stack_effect("abs"), Some(StackEffect::new(1, 1)));
+    assert_eq!(f.stack_effect("sign"), Some(StackEffect::new(1, 1)));
+    assert_eq!(
+        f.eval(": bad ( a -- ) if drop else drop then ;"),
+        Err(Error::StackEffectMismatch)
+    );
+}
+
+#[test]
+fn declarations_that_cannot_be_checked_are_trusted() {
+    let mut f = Forth::new();
+    assert!(f.eval(": odd ( a -- b c ) if 1 then ;").is_ok());
+    assert_eq!(f.stack_effect("odd"), Some(StackEffect::new(1, 2)));
+    assert!(f.eval(": branchy dup if drop then ;").is_ok());
+    assert_eq!(f.stack_effect("branchy"), None);
+}
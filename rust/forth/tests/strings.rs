@@ -0,0 +1,146 @@
+use forth::*;
+
+#[test]
+fn s_quote_and_type_print_text() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#"s" Hello, world!" type"#).is_ok());
+    assert!(f.eval(r#": greet S" hi there" TYPE ; greet greet"#).is_ok());
+    assert_eq!(f.output(), "Hello, world!hi therehi there");
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn strings_round_trip_through_rust() {
+    let mut f = Forth::new();
+    assert!(f.push_str("héllo").is_ok());
+    assert_eq!(f.stack()[1], 6);
+    assert_eq!(f.pop_string().as_deref(), Ok("héllo"));
+    assert!(f.eval(r#"s" from forth""#).is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("from forth"));
+    assert!(f.stack().is_empty());
+}
+
+#[test]
+fn transient_strings_reuse_data_space() {
+    let mut f = Forth::new();
+    for _ in 0..1000 {
+        assert!(f.push_str("from rust").is_ok());
+        assert_eq!(f.pop_string().as_deref(), Ok("from rust"));
+        assert!(f.eval(r#"s" from forth" drop drop"#).is_ok());
+    }
+    assert!(f.eval("1 buffer: probe probe").is_ok());
+    assert!(f.stack()[0] < 1000);
+}
+
+#[test]
+fn the_last_eight_transient_strings_stay_valid() {
+    let mut f = Forth::new();
+    for text in ["a", "b", "c", "d", "e", "f", "g"] {
+        assert!(f.push_str(text).is_ok());
+    }
+    assert!(f.eval(r#"s" longer than the rest""#).is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("longer than the rest"));
+    for text in ["g", "f", "e", "d", "c", "b", "a"] {
+        assert_eq!(f.pop_string().as_deref(), Ok(text));
+    }
+}
+
+#[test]
+fn compiled_strings_are_not_transient() {
+    let mut f = Forth::new();
+    let program = f.compile(r#"s" kept""#).unwrap();
+    for text in ["a", "b", "c", "d", "e", "f", "g", "h", "i"] {
+        assert!(f.push_str(text).is_ok());
+    }
+    assert!(f.run(&program).is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("kept"));
+}
+
+#[test]
+fn s_quote_strings_are_counted() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#"s" abc" drop 1 - count"#).is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("abc"));
+}
+
+#[test]
+fn compare_orders_strings() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#"s" apple" s" apple" compare"#).is_ok());
+    assert!(f.eval(r#"s" apple" s" banana" compare"#).is_ok());
+    assert!(f.eval(r#"s" apples" s" apple" compare"#).is_ok());
+    assert_eq!(f.stack(), [0, -1, 1]);
+}
+
+#[test]
+fn search_finds_a_substring() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#"s" one two three" s" two" search"#).is_ok());
+    assert_eq!(f.stack()[2], -1);
+    assert!(f.eval("drop").is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("two three"));
+
+    assert!(f.eval(r#"s" one two" s" four" search"#).is_ok());
+    assert_eq!(f.stack()[2], 0);
+    assert!(f.eval("drop").is_ok());
+    assert_eq!(f.pop_string().as_deref(), Ok("one two"));
+}
+
+#[test]
+fn slash_string_drops_leading_characters() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#"s" forth" 2 /string type"#).is_ok());
+    assert_eq!(f.output(), "rth");
+}
+
+#[test]
+fn cmove_copies_into_a_buffer() {
+    let mut f = Forth::new();
+    assert!(f.eval("8 buffer: line").is_ok());
+    assert!(f.eval(r#"s" word" drop line 4 cmove line 4 type"#).is_ok());
+    assert!(f.eval("33 line 4 + c! line 5 type line 5 + c@").is_ok());
+    assert_eq!(f.output(), "wordword!");
+    assert_eq!(f.stack(), [0]);
+}
+
+#[test]
+fn overlapping_cmove_propagates_forwards() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#"s" abcd" drop dup dup 1 + 3 cmove"#).is_ok());
+    assert!(f.eval("4 type").is_ok());
+    assert_eq!(f.output(), "aaaa");
+}
+
+#[test]
+fn string_words_have_stack_effects() {
+    let mut f = Forth::new();
+    assert!(f.eval(r#": shout ( -- ) s" HEY" type ;"#).is_ok());
+    assert_eq!(f.stack_effect("shout"), Some(StackEffect::new(0, 0)));
+    assert_eq!(f.stack_effect("search"), Some(StackEffect::new(4, 3)));
+    assert!(f.eval("see shout").is_ok());
+    assert_eq!(f.output(), ": shout s\" HEY\" type ;\n");
+}
+
+#[test]
+fn bad_addresses_leave_the_stack_alone() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("-1 5 type"), Err(Error::InvalidAddress));
+    assert_eq!(f.stack(), [-1, 5]);
+    assert_eq!(f.pop_string(), Err(Error::InvalidAddress));
+    assert_eq!(f.stack(), [-1, 5]);
+    assert_eq!(f.eval("drop drop 1000 c@"), Err(Error::InvalidAddress));
+    assert_eq!(f.eval("drop 5 type"), Err(Error::StackUnderflow));
+    assert_eq!(f.stack(), [5]);
+}
+
+#[test]
+fn data_space_is_bounded() {
+    let mut f = Forth::new();
+    assert_eq!(
+        f.eval("2147483647 buffer: huge"),
+        Err(Error::DataSpaceOverflow)
+    );
+    assert_eq!(f.eval("huge"), Err(Error::UnknownWord));
+    assert_eq!(f.eval("-1 buffer: negative"), Err(Error::InvalidAddress));
+    assert_eq!(f.stack(), [2147483647, -1]);
+}
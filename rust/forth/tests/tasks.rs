@@ -0,0 +1,101 @@
+use forth::*;
+
+fn spawn(f: &mut Forth, source: &str) -> TaskId {
+    let program = f.compile(source).unwrap();
+    f.spawn(&program)
+}
+
+#[test]
+fn a_task_runs_to_the_end() {
+    let mut f = Forth::new();
+    let task = spawn(&mut f, "1 2 +");
+    assert_eq!(f.run_slice(), Some((task, Status::Finished(vec![3]))));
+    assert_eq!(f.run_slice(), None);
+}
+
+#[test]
+fn tasks_take_turns_at_pause() {
+    let mut f = Forth::new();
+    assert!(f.eval(": say s\" a\" type pause s\" b\" type ;").is_ok());
+    let first = spawn(&mut f, "say 1");
+    let second = spawn(&mut f, "say 2");
+    assert_eq!(f.task_count(), 2);
+
+    assert_eq!(f.run_slice(), Some((first, Status::Paused)));
+    assert_eq!(f.run_slice(), Some((second, Status::Paused)));
+    assert_eq!(f.output(), "aa");
+    assert_eq!(f.run_slice(), Some((first, Status::Finished(vec![1]))));
+    assert_eq!(f.run_slice(), Some((second, Status::Finished(vec![2]))));
+    assert_eq!(f.output(), "aabb");
+    assert_eq!(f.task_count(), 0);
+}
+
+#[test]
+fn each_task_has_its_own_stacks() {
+    let mut f = Forth::new();
+    assert!(f.eval(": step {: n :} n pause n 1 + ; 100").is_ok());
+    let a = spawn(&mut f, "10 step");
+    let b = spawn(&mut f, "20 step step");
+
+    assert_eq!(f.run_slice(), Some((a, Status::Paused)));
+    assert_eq!(f.run_slice(), Some((b, Status::Paused)));
+    assert_eq!(f.task_stack(a), Some(&[10][..]));
+    assert_eq!(f.task_stack(b), Some(&[20][..]));
+    assert_eq!(f.stack(), [100]);
+
+    assert_eq!(f.run_slice(), Some((a, Status::Finished(vec![10, 11]))));
+    assert_eq!(f.run_slice(), Some((b, Status::Paused)));
+    assert_eq!(f.task_stack(a), None);
+    assert_eq!(f.run_slice(), Some((b, Status::Finished(vec![20, 21, 22]))));
+    assert_eq!(f.stack(), [100]);
+}
+
+#[test]
+fn tasks_see_later_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": get 1 ;").is_ok());
+    let task = spawn(&mut f, "pause get");
+    assert_eq!(f.run_slice(), Some((task, Status::Paused)));
+    assert!(f.eval(": get 2 ;").is_ok());
+    assert_eq!(f.run_slice(), Some((task, Status::Finished(vec![1]))));
+}
+
+#[test]
+fn a_failing_task_does_not_stop_the_others() {
+    let mut f = Forth::new();
+    let bad = spawn(&mut f, "pause 1 0 /");
+    let good = spawn(&mut f, "pause 5");
+    assert_eq!(f.run_slice(), Some((bad, Status::Paused)));
+    assert_eq!(f.run_slice(), Some((good, Status::Paused)));
+    assert_eq!(
+        f.run_slice(),
+        Some((bad, Status::Failed(Error::DivisionByZero)))
+    );
+    assert_eq!(f.run_slice(), Some((good, Status::Finished(vec![5]))));
+    assert_eq!(f.run_slice(), None);
+}
+
+#[test]
+fn pause_outside_a_task_does_nothing() {
+    let mut f = Forth::new();
+    assert!(f.eval(": wait pause 1 ; wait pause 2").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+    assert_eq!(f.stack_effect("pause"), Some(StackEffect::new(0, 0)));
+}
+
+#[test]
+fn looping_tasks_interleave() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": tick dup if s\" .\" type pause 1 - recurse then ;")
+            .is_ok()
+    );
+    spawn(&mut f, "3 tick");
+    spawn(&mut f, "2 tick");
+    let mut turns = 0;
+    while f.run_slice().is_some() {
+        turns += 1;
+    }
+    assert_eq!(turns, 7);
+    assert_eq!(f.output(), ".....");
+}
@@ -0,0 +1,120 @@
+use forth::*;
+
+#[test]
+fn definitions_go_into_the_current_vocabulary() {
+    let mut f = Forth::new();
+    assert!(f.eval("vocabulary tools also tools definitions").is_ok());
+    assert!(f.eval(": helper 42 ;").is_ok());
+    assert!(f.eval("helper").is_ok());
+    assert_eq!(f.stack(), [42]);
+    assert!(f.eval("previous").is_ok());
+    assert_eq!(f.eval("helper"), Err(Error::UnknownWord));
+}
+
+#[test]
+fn the_first_vocabulary_in_the_search_order_wins() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": greet 1 ; vocabulary loud also loud definitions")
+            .is_ok()
+    );
+    assert!(f.eval(": greet 2 ; greet").is_ok());
+    assert!(f.eval("forth greet").is_ok());
+    assert_eq!(f.stack(), [2, 1]);
+}
+
+#[test]
+fn see_shows_a_vocabulary_by_name() {
+    let mut f = Forth::new();
+    assert!(f.eval("vocabulary tools see tools").is_ok());
+    let source = f.take_output();
+    assert_eq!(source, ": tools tools ;\n");
+    assert!(f.eval(&source).is_ok());
+    assert!(
+        f.eval("also tools definitions : helper 42 ; previous")
+            .is_ok()
+    );
+    assert_eq!(f.eval("helper"), Err(Error::UnknownWord));
+    assert!(f.eval("also tools helper").is_ok());
+    assert_eq!(f.stack(), [42]);
+}
+
+#[test]
+fn order_shows_the_search_order_and_current_vocabulary() {
+    let mut f = Forth::new();
+    assert!(f.eval("vocabulary Tools also tools order").is_ok());
+    assert!(f.eval("definitions only order").is_ok());
+    assert_eq!(f.output(), "tools forth    forth\nforth    tools\n");
+}
+
+#[test]
+fn words_lists_the_first_vocabulary() {
+    let mut f = Forth::new();
+    assert!(
+        f.eval(": a ; vocabulary extra also extra definitions : b ;")
+            .is_ok()
+    );
+    assert_eq!(f.words().collect::<Vec<_>>(), ["b"]);
+    assert!(f.eval("previous").is_ok());
+    assert_eq!(f.words().collect::<Vec<_>>(), ["extra", "a"]);
+}
+
+#[test]
+fn an_empty_search_order_is_an_error() {
+    let mut f = Forth::new();
+    assert!(f.eval("previous").is_ok());
+    assert_eq!(f.eval("also"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("definitions"), Err(Error::InvalidWord));
+    assert!(f.eval("1 2 +").is_ok());
+    assert_eq!(f.stack(), [3]);
+    assert!(f.eval("only").is_ok());
+}
+
+#[test]
+fn marker_rolls_back_the_dictionary() {
+    let mut f = Forth::new();
+    assert!(f.eval(": keep 1 ; marker scratch").is_ok());
+    assert!(
+        f.eval(": keep 2 ; : temp 3 ; vocabulary v also v definitions")
+            .is_ok()
+    );
+    assert!(f.eval("scratch keep").is_ok());
+    assert_eq!(f.stack(), [1]);
+    assert_eq!(f.eval("temp"), Err(Error::UnknownWord));
+    assert_eq!(f.eval("scratch"), Err(Error::UnknownWord));
+    assert_eq!(f.words().collect::<Vec<_>>(), ["keep"]);
+}
+
+#[test]
+fn forget_removes_a_word_and_everything_after_it() {
+    let mut f = Forth::new();
+    assert!(f.eval(": a 1 ; : b 2 ; : a 3 ; : c 4 ;").is_ok());
+    assert!(f.eval("forget a").is_ok());
+    assert_eq!(f.eval("c"), Err(Error::UnknownWord));
+    assert!(f.eval("a b").is_ok());
+    assert_eq!(f.stack(), [1, 2]);
+}
+
+#[test]
+fn compiled_words_keep_forgotten_definitions() {
+    let mut f = Forth::new();
+    assert!(f.eval(": base 10 ; : user base 1 + ;").is_ok());
+    assert!(f.eval("forget user forget base").is_ok());
+    assert_eq!(f.eval("base"), Err(Error::UnknownWord));
+    assert!(f.eval(": base 20 ;").is_ok());
+
+    assert!(f.eval(": ten 10 ; marker undo : eleven ten 1 + ;").is_ok());
+    let program = f.compile("eleven").unwrap();
+    assert!(f.eval("undo").is_ok());
+    assert!(f.run(&program).is_ok());
+    assert_eq!(f.stack(), [11]);
+}
+
+#[test]
+fn forgetting_unknown_and_builtin_words_fails() {
+    let mut f = Forth::new();
+    assert_eq!(f.eval("forget nothing"), Err(Error::UnknownWord));
+    assert_eq!(f.eval("forget dup"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("forget"), Err(Error::InvalidWord));
+    assert_eq!(f.eval("vocabulary 5"), Err(Error::InvalidWord));
+}